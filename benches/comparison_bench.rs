@@ -6,6 +6,7 @@
 //! - Manual DI patterns (baseline)
 
 use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use seq_macro::seq;
 use std::hint::black_box;
 use std::sync::Arc;
 
@@ -521,6 +522,113 @@ fn bench_service_count_scaling(c: &mut Criterion) {
     group.finish();
 }
 
+// ============================================================================
+// NxM Concurrent Write Contention Benchmark Matrix
+// ============================================================================
+
+// One genuinely distinct type per index (0..256), so the matrix below can
+// register real distinct TypeIds under write contention instead of repeatedly
+// overwriting a single slot.
+seq!(N in 0..256 {
+    struct NxMSvc~N(u64);
+});
+
+/// N writer threads each registering M distinct service types concurrently
+/// into one shared container, in the style of Solana bucket_map's
+/// `DEFINE_NxM_BENCH` matrix - but for registration instead of lookup.
+fn bench_nxm_write_contention(c: &mut Criterion) {
+    use std::thread;
+
+    let mut group = c.benchmark_group("nxm_write_contention");
+
+    macro_rules! nxm_case {
+        ($num_threads:expr, $num_regs:literal) => {{
+            let num_threads: usize = $num_threads;
+            group.throughput(Throughput::Elements((num_threads * $num_regs) as u64));
+            let label = format!("{}x{}", num_threads, $num_regs);
+
+            // HashMap with RwLock
+            group.bench_with_input(
+                BenchmarkId::new("hashmap_rwlock", &label),
+                &num_threads,
+                |b, &n| {
+                    b.iter(|| {
+                        let container = Arc::new(hashmap_di::Container::new());
+                        let handles: Vec<_> = (0..n)
+                            .map(|_| {
+                                let container = Arc::clone(&container);
+                                thread::spawn(move || {
+                                    seq!(N in 0..$num_regs {
+                                        container.register(black_box(NxMSvc~N(0)));
+                                    });
+                                })
+                            })
+                            .collect();
+                        for h in handles {
+                            h.join().unwrap();
+                        }
+                    })
+                },
+            );
+
+            // DashMap
+            group.bench_with_input(
+                BenchmarkId::new("dashmap_basic", &label),
+                &num_threads,
+                |b, &n| {
+                    b.iter(|| {
+                        let container = Arc::new(dashmap_di::Container::new());
+                        let handles: Vec<_> = (0..n)
+                            .map(|_| {
+                                let container = Arc::clone(&container);
+                                thread::spawn(move || {
+                                    seq!(N in 0..$num_regs {
+                                        container.register(black_box(NxMSvc~N(0)));
+                                    });
+                                })
+                            })
+                            .collect();
+                        for h in handles {
+                            h.join().unwrap();
+                        }
+                    })
+                },
+            );
+
+            // dependency-injector
+            group.bench_with_input(
+                BenchmarkId::new("dependency_injector", &label),
+                &num_threads,
+                |b, &n| {
+                    b.iter(|| {
+                        let container = Arc::new(dependency_injector::Container::new());
+                        let handles: Vec<_> = (0..n)
+                            .map(|_| {
+                                let container = Arc::clone(&container);
+                                thread::spawn(move || {
+                                    seq!(N in 0..$num_regs {
+                                        container.singleton(black_box(NxMSvc~N(0)));
+                                    });
+                                })
+                            })
+                            .collect();
+                        for h in handles {
+                            h.join().unwrap();
+                        }
+                    })
+                },
+            );
+        }};
+    }
+
+    nxm_case!(1, 64);
+    nxm_case!(2, 64);
+    nxm_case!(4, 128);
+    nxm_case!(8, 256);
+
+    group.finish();
+}
+
 criterion_group!(
     comparison_benches,
     bench_singleton_resolution,
@@ -530,6 +638,7 @@ criterion_group!(
     bench_concurrent_reads,
     bench_mixed_workload,
     bench_service_count_scaling,
+    bench_nxm_write_contention,
 );
 
 criterion_main!(comparison_benches);
@@ -3,6 +3,7 @@
 //! This crate provides derive macros for automatic dependency injection:
 //!
 //! - `#[derive(Inject)]` - Generate `from_container()` for runtime DI
+//! - `#[derive(AsyncInject)]` - Generate an async `from_container()` for dependencies resolved via `Container::get_async`
 //! - `#[derive(Service)]` - Generate `Service` trait impl for compile-time verified DI
 //!
 //! # Inject Example
@@ -61,27 +62,80 @@
 
 use proc_macro::TokenStream;
 use quote::quote;
+use syn::ext::IdentExt;
 use syn::{parse_macro_input, DeriveInput, Data, Fields, Type, Attribute};
 
+/// A single flag inside a field/struct-level macro attribute, e.g. the
+/// `optional`, `all`, `dyn` in `#[inject(optional, dyn)]`, or a `name =
+/// "..."` pair. Parsed with `Ident::parse_any` rather than `syn::Meta` so
+/// reserved words like `dyn` can be used as flag names.
+struct AttrFlag {
+    key: syn::Ident,
+    value: Option<syn::Expr>,
+}
+
+impl syn::parse::Parse for AttrFlag {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let key = syn::Ident::parse_any(input)?;
+        let value = if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            Some(input.parse::<syn::Expr>()?)
+        } else {
+            None
+        };
+        Ok(AttrFlag { key, value })
+    }
+}
+
+/// Parse a macro attribute's comma-separated flag list, e.g. the `optional,
+/// name = "primary"` in `#[inject(optional, name = "primary")]`. Returns an
+/// empty list (rather than erroring) for an attribute with no parenthesized
+/// arguments at all, e.g. bare `#[inject]`.
+fn parse_attr_flags(attr: &Attribute) -> Vec<AttrFlag> {
+    attr.parse_args_with(syn::punctuated::Punctuated::<AttrFlag, syn::Token![,]>::parse_terminated)
+        .map(|flags| flags.into_iter().collect())
+        .unwrap_or_default()
+}
+
 /// Derive macro for automatic dependency injection.
 ///
 /// Generates a `from_container()` method that resolves dependencies
-/// from a `Container` instance.
+/// from a `Container` instance, and a `registration()` method that
+/// autowires the constructor into a `ProviderRegistration` for use with
+/// `ContainerBuilder` or the `provider!` module system.
 ///
 /// # Attributes
 ///
 /// - `#[inject]` - Mark a field for injection. The field type must be `Arc<T>`.
 /// - `#[inject(optional)]` - Mark a field as optional injection. Uses `Option<Arc<T>>`.
+/// - `#[inject(all)]` - Fill the field with every implementation registered via
+///   `Container::register_many::<Trait>`. The field type must be `Vec<Arc<Trait>>`.
+/// - `#[inject(name = "...")]` - Fill the field from the keyed binding registered via
+///   `Container::register_named::<Trait>(name, ...)`. The field type must be `Arc<Trait>`.
+/// - `#[inject(optional, name = "...")]` - Same as above, but missing is tolerated. The
+///   field type must be `Option<Arc<Trait>>`.
+/// - `#[inject(dyn)]` - Fill the field from a trait interface bound via
+///   `Container::bind`/`bind_interface`. The field type must be `Arc<dyn Trait>`.
+/// - `#[inject(optional, dyn)]` - Same as above, but unbound is tolerated. The field
+///   type must be `Option<Arc<dyn Trait>>`.
+/// - `#[inject(singleton)]` / `#[inject(lazy)]` / `#[inject(transient)]` on the struct
+///   itself - select the `Lifetime` used by the generated `registration()`. Defaults
+///   to `singleton`.
 ///
 /// # Generated Methods
 ///
-/// - `from_container(container: &Container) -> Result<Self, DiError>` - Creates an instance
-///   by resolving all `#[inject]` fields from the container.
+/// - `from_container(container: &Container) -> Result<Self, ResolutionError>` - Creates an
+///   instance by resolving all `#[inject]` fields from the container. Fails with a
+///   `ResolutionError` naming the first unresolvable dependency and the chain of
+///   `#[derive(Inject)]` structs under construction above it.
+/// - `registration() -> ProviderRegistration` - Wires `from_container` up as a
+///   `register_fn`, so the struct can self-register with a `ContainerBuilder`.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// #[derive(Inject)]
+/// #[inject(transient)]
 /// struct MyService {
 ///     #[inject]
 ///     db: Arc<Database>,
@@ -98,6 +152,8 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
     let name = &input.ident;
     let generics = &input.generics;
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let lifetime = find_lifetime_attr(&input.attrs);
+    let construct_with = find_construct_with_attr(&input.attrs);
 
     // Only support structs with named fields
     let fields = match &input.data {
@@ -124,6 +180,10 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
 
     // Parse fields and generate initialization code
     let mut field_inits = Vec::new();
+    // Resolved-dependency expressions in declaration order, used only when
+    // `construct_with` is set - non-injected fields have no place in that
+    // tuple, since the user function builds the whole `Self`.
+    let mut injected_exprs = Vec::new();
 
     for field in fields.iter() {
         let field_name = field.ident.as_ref().unwrap();
@@ -131,13 +191,11 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
 
         let inject_attr = find_inject_attr(&field.attrs);
 
-        match inject_attr {
+        let expr = match inject_attr {
             Some(InjectAttr::Required) => {
                 // Extract inner type from Arc<T>
                 if let Some(inner_type) = extract_arc_inner_type(field_type) {
-                    field_inits.push(quote! {
-                        #field_name: container.get::<#inner_type>()?
-                    });
+                    quote! { container.get::<#inner_type>()? }
                 } else {
                     return syn::Error::new_spanned(
                         field_type,
@@ -150,9 +208,283 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
             Some(InjectAttr::Optional) => {
                 // Extract inner type from Option<Arc<T>>
                 if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    quote! { container.try_get::<#inner_type>() }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(optional)] must have type Option<Arc<T>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::All) => {
+                // Extract inner Trait from Vec<Arc<Trait>>
+                if let Some(inner_type) = extract_vec_arc_inner_type(field_type) {
+                    quote! { container.resolve_all::<#inner_type>() }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(all)] must have type Vec<Arc<T>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::Named(key)) => {
+                // Extract inner Trait from Arc<Trait>
+                if let Some(inner_type) = extract_arc_inner_type(field_type) {
+                    quote! { container.get_named::<#inner_type>(#key)? }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(name = \"...\")] must have type Arc<T>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::NamedOptional(key)) => {
+                // Extract inner Trait from Option<Arc<Trait>>
+                if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    quote! { container.resolve_named::<#inner_type>(#key) }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(optional, name = \"...\")] must have type Option<Arc<T>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::Dyn) => {
+                // Extract inner Trait from Arc<dyn Trait>
+                if let Some(inner_type) = extract_arc_inner_type(field_type) {
+                    quote! { container.get_dyn::<#inner_type>()? }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(dyn)] must have type Arc<dyn Trait>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::DynOptional) => {
+                // Extract inner Trait from Option<Arc<dyn Trait>>
+                if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    quote! { container.try_get_dyn::<#inner_type>() }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject(optional, dyn)] must have type Option<Arc<dyn Trait>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            None => {
+                // Non-injected field - use Default, unless a construct_with
+                // function is taking over the whole struct literal.
+                if construct_with.is_none() {
                     field_inits.push(quote! {
-                        #field_name: container.try_get::<#inner_type>()
+                        #field_name: ::std::default::Default::default()
                     });
+                }
+                continue;
+            }
+        };
+
+        injected_exprs.push(expr.clone());
+        field_inits.push(quote! { #field_name: #expr });
+    }
+
+    let register_fn_body = match lifetime {
+        InjectLifetime::Singleton => quote! {
+            let instance = Self::from_container(container)
+                .unwrap_or_else(|err| panic!("failed to autowire {}: {}", ::std::any::type_name::<#name #ty_generics>(), err));
+            container.singleton(instance);
+        },
+        InjectLifetime::Lazy => quote! {
+            let container = container.clone();
+            container.lazy(move || {
+                Self::from_container(&container)
+                    .unwrap_or_else(|err| panic!("failed to autowire {}: {}", ::std::any::type_name::<#name #ty_generics>(), err))
+            });
+        },
+        InjectLifetime::Transient => quote! {
+            let container = container.clone();
+            container.transient(move || {
+                Self::from_container(&container)
+                    .unwrap_or_else(|err| panic!("failed to autowire {}: {}", ::std::any::type_name::<#name #ty_generics>(), err))
+            });
+        },
+    };
+
+    // Body of `from_container`: either a plain struct literal, or - when
+    // `#[inject(construct_with = ...)]` is present - the resolved
+    // dependencies handed to that function (a tuple for two or more, the
+    // bare value for one, `()` for none).
+    let from_container_body = match &construct_with {
+        None => quote! {
+            Ok(Self {
+                #(#field_inits),*
+            })
+        },
+        Some(construct_fn) => {
+            let deps_tuple = match injected_exprs.len() {
+                0 => quote! { () },
+                1 => {
+                    let expr = &injected_exprs[0];
+                    quote! { #expr }
+                }
+                _ => quote! { (#(#injected_exprs),*) },
+            };
+            quote! {
+                #construct_fn(#deps_tuple).map_err(|reason| {
+                    ::dependency_injector::ResolutionError::from(
+                        ::dependency_injector::DiError::creation_failed::<#name #ty_generics>(reason)
+                    )
+                })
+            }
+        }
+    };
+
+    // Generate the implementation
+    let expanded = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Create an instance by resolving dependencies from a container.
+            ///
+            /// All fields marked with `#[inject]` will be resolved from the container.
+            /// Fields not marked with `#[inject]` will use `Default::default()`, unless
+            /// `#[inject(construct_with = path::to::fn)]` is set on the struct, in which
+            /// case the resolved `#[inject]` values are passed to that function to
+            /// build `Self` (as a tuple in declaration order for two or more, as the
+            /// bare value for one, and as `()` for none) - useful
+            /// for fields without a `Default` impl, or that need post-resolution
+            /// validation a struct literal can't express.
+            ///
+            /// Returns a `ResolutionError` rather than a bare `DiError` - if a field
+            /// deep in a chain of `#[derive(Inject)]` structs can't be resolved, the
+            /// error carries the full chain under construction (e.g.
+            /// `ApiController -> UserService -> Cache`), and a struct that requires
+            /// itself (directly or transitively) is caught as `ResolutionError::Cycle`
+            /// instead of recursing until the stack overflows.
+            pub fn from_container(
+                container: &::dependency_injector::Container
+            ) -> ::std::result::Result<Self, ::dependency_injector::ResolutionError> {
+                let _span = ::dependency_injector::trace_from_container_enter(
+                    ::std::any::type_name::<#name #ty_generics>()
+                );
+                let _frame = ::dependency_injector::enter_resolution_frame(
+                    ::std::any::TypeId::of::<#name #ty_generics>(),
+                    ::std::any::type_name::<#name #ty_generics>(),
+                )?;
+                #from_container_body
+            }
+
+            /// Build a `ProviderRegistration` that autowires this type's constructor
+            /// from a `Container`, using the lifetime declared via `#[inject(..)]`
+            /// on the struct (defaults to `singleton`).
+            pub fn registration() -> ::dependency_injector::ProviderRegistration {
+                ::dependency_injector::ProviderRegistration {
+                    type_id: ::std::any::TypeId::of::<#name #ty_generics>(),
+                    type_name: ::std::any::type_name::<#name #ty_generics>(),
+                    register_fn: |container| {
+                        #register_fn_body
+                    },
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Same field-level `#[inject]`/`#[inject(optional)]` attributes `derive_inject`
+/// reads, but emitting an `async fn from_container` that awaits each
+/// dependency through `Container::get_async`/`try_get_async` instead of the
+/// synchronous `get`/`try_get` - for services whose dependencies come from
+/// async factories (a connecting-on-first-use database pool, a network
+/// client) and so can't be resolved from a blocking context.
+///
+/// `#[inject(all)]`, `#[inject(name = "...")]` and `#[inject(dyn)]` have no
+/// async-resolving counterpart on `Container` yet, so a field using any of
+/// them is a compile error here rather than silently falling back to the
+/// synchronous method.
+///
+/// Unlike `derive_inject`, the generated `from_container` does *not* call
+/// `enter_resolution_frame` - so a cycle among `#[derive(AsyncInject)]`
+/// structs recurses until the stack overflows rather than returning
+/// `ResolutionError::Cycle`, and errors bubbling out of a nested
+/// `from_container` carry no accumulated dependency path. This is a
+/// deliberate omission, not an oversight: `enter_resolution_frame`'s guard
+/// pops a thread-local (`RESOLUTION_STACK`) on `Drop`, which only works
+/// correctly when push and pop run on the same thread. An `async fn` can
+/// suspend at an `.await` and resume on a different worker thread on a
+/// multi-threaded executor (e.g. any of Tokio's default runtimes), so the
+/// guard could pop an unrelated frame - or none at all - on whatever thread
+/// happens to drive it to completion, silently corrupting cycle detection
+/// for other resolutions on that thread instead of catching this one's.
+/// Fixing this for real needs the path threaded as an explicit argument
+/// through `get_async`/`try_get_async` rather than carried in a thread-local.
+#[proc_macro_derive(AsyncInject, attributes(inject))]
+pub fn derive_async_inject(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let name = &input.ident;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // Only support structs with named fields
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return syn::Error::new_spanned(
+                    &input,
+                    "AsyncInject can only be derived for structs with named fields"
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(
+                &input,
+                "AsyncInject can only be derived for structs"
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let mut field_inits = Vec::new();
+
+    for field in fields.iter() {
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+
+        let inject_attr = find_inject_attr(&field.attrs);
+
+        let expr = match inject_attr {
+            Some(InjectAttr::Required) => {
+                // Extract inner type from Arc<T>
+                if let Some(inner_type) = extract_arc_inner_type(field_type) {
+                    quote! { container.get_async::<#inner_type>().await? }
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[inject] must have type Arc<T>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(InjectAttr::Optional) => {
+                // Extract inner type from Option<Arc<T>>
+                if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    quote! { container.try_get_async::<#inner_type>().await }
                 } else {
                     return syn::Error::new_spanned(
                         field_type,
@@ -162,23 +494,48 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
                     .into();
                 }
             }
+            Some(InjectAttr::All)
+            | Some(InjectAttr::Named(_))
+            | Some(InjectAttr::NamedOptional(_))
+            | Some(InjectAttr::Dyn)
+            | Some(InjectAttr::DynOptional) => {
+                return syn::Error::new_spanned(
+                    field_type,
+                    "#[derive(AsyncInject)] only supports #[inject] and #[inject(optional)] - \
+                     `Container` has no async-resolving counterpart for `all`/`name`/`dyn` yet"
+                )
+                .to_compile_error()
+                .into();
+            }
             None => {
-                // Non-injected field - use Default
+                // Non-injected field - use Default, same as `derive_inject`.
                 field_inits.push(quote! {
                     #field_name: ::std::default::Default::default()
                 });
+                continue;
             }
-        }
+        };
+
+        field_inits.push(quote! { #field_name: #expr });
     }
 
-    // Generate the implementation
     let expanded = quote! {
         impl #impl_generics #name #ty_generics #where_clause {
-            /// Create an instance by resolving dependencies from a container.
+            /// Create an instance by asynchronously resolving dependencies
+            /// from a container.
             ///
-            /// All fields marked with `#[inject]` will be resolved from the container.
-            /// Fields not marked with `#[inject]` will use `Default::default()`.
-            pub fn from_container(
+            /// All fields marked with `#[inject]` are awaited through
+            /// `Container::get_async`; `#[inject(optional)]` fields through
+            /// `try_get_async`. Fields not marked with `#[inject]` use
+            /// `Default::default()`. The returned future holds nothing
+            /// non-`Send` across its `.await` points, so it can be spawned
+            /// onto an executor like any other task.
+            ///
+            /// Unlike `#[derive(Inject)]`'s `from_container`, a cycle among
+            /// `AsyncInject` structs is not caught - it recurses until the
+            /// stack overflows - and errors carry no accumulated dependency
+            /// path. See the `AsyncInject` derive macro's own docs for why.
+            pub async fn from_container(
                 container: &::dependency_injector::Container
             ) -> ::dependency_injector::Result<Self> {
                 Ok(Self {
@@ -195,26 +552,106 @@ pub fn derive_inject(input: TokenStream) -> TokenStream {
 enum InjectAttr {
     Required,
     Optional,
+    /// `#[inject(all)]` - fill from every `Container::register_many` entry.
+    All,
+    /// `#[inject(name = "...")]` - fill from a `Container::register_named` entry.
+    Named(String),
+    /// `#[inject(optional, name = "...")]` - same, but missing is tolerated.
+    NamedOptional(String),
+    /// `#[inject(dyn)]` - fill from a `Container::bind`/`bind_interface` entry.
+    Dyn,
+    /// `#[inject(optional, dyn)]` - same, but unbound is tolerated.
+    DynOptional,
+}
+
+/// Lifetime selected for the `registration()` method generated by `#[derive(Inject)]`.
+enum InjectLifetime {
+    Singleton,
+    Lazy,
+    Transient,
 }
 
-/// Find and parse the #[inject] attribute
+/// Find a struct-level `#[inject(singleton|lazy|transient)]` attribute.
+///
+/// Defaults to `Singleton` if absent, matching `Lifetime`'s own default. Can
+/// appear combined with `construct_with = ...` in the same attribute, e.g.
+/// `#[inject(transient, construct_with = Self::build)]`.
+fn find_lifetime_attr(attrs: &[Attribute]) -> InjectLifetime {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("inject")) {
+        for flag in parse_attr_flags(attr) {
+            match flag.key.to_string().as_str() {
+                "lazy" => return InjectLifetime::Lazy,
+                "transient" => return InjectLifetime::Transient,
+                "singleton" => return InjectLifetime::Singleton,
+                _ => {}
+            }
+        }
+    }
+    InjectLifetime::Singleton
+}
+
+/// Find a struct-level `#[inject(construct_with = path::to::fn)]` attribute.
+///
+/// When present, `from_container` resolves every `#[inject]` field, packs
+/// the results into a tuple in declaration order (a single result is passed
+/// bare, none as `()`), and hands them to this
+/// function instead of building `Self` via a field-by-field struct literal -
+/// see `derive_inject`'s use of this for the generated body.
+fn find_construct_with_attr(attrs: &[Attribute]) -> Option<syn::Path> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("inject")) {
+        for flag in parse_attr_flags(attr) {
+            if flag.key == "construct_with" {
+                if let Some(syn::Expr::Path(expr_path)) = flag.value {
+                    return Some(expr_path.path);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Find and parse the #[inject] attribute.
+///
+/// Accepts any comma-separated combination of `optional`, `all`, `dyn`, and
+/// `name = "..."` (e.g. `#[inject(optional, name = "primary")]`), not just
+/// the single-flag forms - `all` takes precedence if combined with the
+/// others, since "all of the named bindings" isn't a meaningful request.
 fn find_inject_attr(attrs: &[Attribute]) -> Option<InjectAttr> {
     for attr in attrs {
         if attr.path().is_ident("inject") {
-            // Check if it has (optional) argument
+            // Bare #[inject]
             if attr.meta.require_path_only().is_ok() {
                 return Some(InjectAttr::Required);
             }
 
-            // Parse inject(optional)
-            if let Ok(nested) = attr.parse_args::<syn::Ident>() {
-                if nested == "optional" {
-                    return Some(InjectAttr::Optional);
+            let mut optional = false;
+            let mut all = false;
+            let mut is_dyn = false;
+            let mut name = None;
+
+            for flag in parse_attr_flags(attr) {
+                match flag.key.to_string().as_str() {
+                    "optional" => optional = true,
+                    "all" => all = true,
+                    "dyn" => is_dyn = true,
+                    "name" => {
+                        if let Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(key), .. })) = flag.value {
+                            name = Some(key.value());
+                        }
+                    }
+                    _ => {}
                 }
             }
 
-            // Default to required
-            return Some(InjectAttr::Required);
+            return Some(match (all, is_dyn, optional, name) {
+                (true, _, _, _) => InjectAttr::All,
+                (false, true, false, _) => InjectAttr::Dyn,
+                (false, true, true, _) => InjectAttr::DynOptional,
+                (false, false, false, Some(key)) => InjectAttr::Named(key),
+                (false, false, true, Some(key)) => InjectAttr::NamedOptional(key),
+                (false, false, true, None) => InjectAttr::Optional,
+                (false, false, false, None) => InjectAttr::Required,
+            });
         }
     }
     None
@@ -250,6 +687,21 @@ fn extract_option_arc_inner_type(ty: &Type) -> Option<&Type> {
     None
 }
 
+/// Extract T from Vec<Arc<T>>
+fn extract_vec_arc_inner_type(ty: &Type) -> Option<&Type> {
+    if let Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == "Vec" {
+            if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                    return extract_arc_inner_type(inner);
+                }
+            }
+        }
+    }
+    None
+}
+
 // =============================================================================
 // Service Derive Macro
 // =============================================================================
@@ -258,31 +710,95 @@ fn extract_option_arc_inner_type(ty: &Type) -> Option<&Type> {
 enum DepAttr {
     Required,
     Optional,
+    /// `#[dep(name = "...")]` - resolved via a generated `NamedKey` marker and
+    /// `verified::Named<Trait, Marker>`. Field type must still be `Arc<Trait>`.
+    Named(String),
+    /// `#[dep(optional, name = "...")]` - same, but missing is tolerated.
+    /// Field type must be `Option<Arc<Trait>>`.
+    NamedOptional(String),
+    /// `#[dep(dyn)]` - resolved via `verified::Dyn<Trait>`, i.e. a trait
+    /// interface bound with `Container::bind`/`bind_interface`. Field type
+    /// must still be `Arc<Trait>`.
+    Dyn,
+    /// `#[dep(optional, dyn)]` - same, but unbound is tolerated. Field type
+    /// must be `Option<Arc<Trait>>`.
+    DynOptional,
 }
 
-/// Find and parse the #[dep] attribute
+/// Find and parse the #[dep] attribute.
+///
+/// Accepts any comma-separated combination of `optional`, `dyn`, and
+/// `name = "..."`, mirroring `find_inject_attr`.
 fn find_dep_attr(attrs: &[Attribute]) -> Option<DepAttr> {
     for attr in attrs {
         if attr.path().is_ident("dep") {
-            // Check if it has (optional) argument
+            // Bare #[dep]
             if attr.meta.require_path_only().is_ok() {
                 return Some(DepAttr::Required);
             }
 
-            // Parse dep(optional)
-            if let Ok(nested) = attr.parse_args::<syn::Ident>() {
-                if nested == "optional" {
-                    return Some(DepAttr::Optional);
+            let mut optional = false;
+            let mut is_dyn = false;
+            let mut name = None;
+
+            for flag in parse_attr_flags(attr) {
+                match flag.key.to_string().as_str() {
+                    "optional" => optional = true,
+                    "dyn" => is_dyn = true,
+                    "name" => {
+                        if let Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(key), .. })) = flag.value {
+                            name = Some(key.value());
+                        }
+                    }
+                    _ => {}
                 }
             }
 
-            // Default to required
-            return Some(DepAttr::Required);
+            return Some(match (is_dyn, optional, name) {
+                (true, false, _) => DepAttr::Dyn,
+                (true, true, _) => DepAttr::DynOptional,
+                (false, false, Some(key)) => DepAttr::Named(key),
+                (false, true, Some(key)) => DepAttr::NamedOptional(key),
+                (false, true, None) => DepAttr::Optional,
+                (false, false, None) => DepAttr::Required,
+            });
         }
     }
     None
 }
 
+/// A single `provides = dyn Trait` pair inside `#[service(...)]`. Parsed
+/// separately from `AttrFlag` because the right-hand side is a `Type`
+/// (`dyn Trait`), not an `Expr` - the two grammars don't overlap.
+struct ProvidesFlag {
+    trait_ty: syn::Type,
+}
+
+impl syn::parse::Parse for ProvidesFlag {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let key = syn::Ident::parse_any(input)?;
+        if key != "provides" {
+            return Err(syn::Error::new(key.span(), "expected `provides = dyn Trait`"));
+        }
+        input.parse::<syn::Token![=]>()?;
+        Ok(ProvidesFlag { trait_ty: input.parse()? })
+    }
+}
+
+/// Find every struct-level `#[service(provides = dyn Trait)]` attribute.
+///
+/// The attribute is repeatable - one `dyn Trait` per `#[service(provides = ...)]`
+/// instance - matching how `#[dep]`/`#[inject]` instances are never combined
+/// across multiple trait bindings on the same field.
+fn find_provides_attrs(attrs: &[Attribute]) -> Vec<syn::Type> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("service"))
+        .filter_map(|attr| attr.parse_args::<ProvidesFlag>().ok())
+        .map(|flag| flag.trait_ty)
+        .collect()
+}
+
 /// Derive macro for the `Service` trait.
 ///
 /// Generates a `Service` implementation with compile-time dependency declaration.
@@ -292,9 +808,30 @@ fn find_dep_attr(attrs: &[Attribute]) -> Option<DepAttr> {
 ///
 /// - `#[dep]` - Mark a field as a required dependency. Must be `Arc<T>`.
 /// - `#[dep(optional)]` - Mark a field as optional. Must be `Option<Arc<T>>`.
+/// - `#[dep(name = "...")]` - Resolve from the `Container::register_named::<Trait>`
+///   entry registered under that name. Must be `Arc<Trait>`. A private, zero-sized
+///   `NamedKey` marker is generated per field to thread the name through
+///   `Self::Dependencies` at the type level - see `verified::Named`. Freely
+///   combines with any other `#[dep]` kind on the same struct (e.g. two
+///   differently-named fields, or a named field alongside a plain `#[dep]`
+///   one) - `Dependencies` tuples are resolved element-by-element via each
+///   element's own `Resolvable` impl, not a single `Arc<T>`-shaped one.
+/// - `#[dep(optional, name = "...")]` - Same, but missing is tolerated. Must be
+///   `Option<Arc<Trait>>`.
+/// - `#[dep(dyn)]` - Resolve from a trait interface bound via
+///   `Container::bind`/`bind_interface`. Must be `Arc<Trait>`. Like
+///   `#[dep(name = "...")]` above, this composes freely with any other
+///   `#[dep]` kind on the same struct.
+/// - `#[dep(optional, dyn)]` - Same, but unbound is tolerated. Must be
+///   `Option<Arc<Trait>>`.
 ///
 /// Fields without `#[dep]` use `Default::default()`.
 ///
+/// A struct-level `#[service(provides = dyn Trait)]` attribute (repeatable) emits a
+/// companion `{Name}Provider` zero-sized struct with a `register(&Container)` method
+/// that binds the concrete type under each declared trait via `bind_interface` - the
+/// macro-layer counterpart to calling `Container::bind` by hand.
+///
 /// # Generated Code
 ///
 /// The macro generates:
@@ -331,7 +868,7 @@ fn find_dep_attr(attrs: &[Attribute]) -> Option<DepAttr> {
 /// //     }
 /// // }
 /// ```
-#[proc_macro_derive(Service, attributes(dep))]
+#[proc_macro_derive(Service, attributes(dep, service))]
 pub fn derive_service(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -366,6 +903,7 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
     let mut dep_types: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut dep_names: Vec<syn::Ident> = Vec::new();
     let mut field_inits: Vec<proc_macro2::TokenStream> = Vec::new();
+    let mut marker_defs: Vec<proc_macro2::TokenStream> = Vec::new();
     let mut dep_index = 0usize;
 
     for field in fields.iter() {
@@ -409,6 +947,94 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
                     .into();
                 }
             }
+            Some(DepAttr::Named(key)) => {
+                // Field is Arc<Trait>; the Dependencies slot is Named<Trait, Marker>
+                // so the create() body unwraps it back down to the plain Arc.
+                if let Some(inner_type) = extract_arc_inner_type(field_type) {
+                    let marker = syn::Ident::new(&format!("__{}NamedKey{}", name, dep_index), field_name.span());
+                    marker_defs.push(quote! {
+                        #[doc(hidden)]
+                        struct #marker;
+                        impl ::dependency_injector::verified::NamedKey for #marker {
+                            const NAME: &'static str = #key;
+                        }
+                    });
+                    let dep_name = syn::Ident::new(&format!("__dep_{}", dep_index), field_name.span());
+                    dep_types.push(quote! { ::dependency_injector::verified::Named<#inner_type, #marker> });
+                    dep_names.push(dep_name.clone());
+                    field_inits.push(quote! { #field_name: #dep_name.0 });
+                    dep_index += 1;
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[dep(name = \"...\")] must have type Arc<T>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(DepAttr::NamedOptional(key)) => {
+                // Field is Option<Arc<Trait>>; the Dependencies slot is
+                // Option<Named<Trait, Marker>>.
+                if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    let marker = syn::Ident::new(&format!("__{}NamedKey{}", name, dep_index), field_name.span());
+                    marker_defs.push(quote! {
+                        #[doc(hidden)]
+                        struct #marker;
+                        impl ::dependency_injector::verified::NamedKey for #marker {
+                            const NAME: &'static str = #key;
+                        }
+                    });
+                    let dep_name = syn::Ident::new(&format!("__dep_{}", dep_index), field_name.span());
+                    dep_types.push(quote! { ::std::option::Option<::dependency_injector::verified::Named<#inner_type, #marker>> });
+                    dep_names.push(dep_name.clone());
+                    field_inits.push(quote! { #field_name: #dep_name.map(|named| named.0) });
+                    dep_index += 1;
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[dep(optional, name = \"...\")] must have type Option<Arc<T>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(DepAttr::Dyn) => {
+                // Field is Arc<Trait>; the Dependencies slot is
+                // verified::Dyn<Trait>, resolved via bind/bind_interface.
+                if let Some(inner_type) = extract_arc_inner_type(field_type) {
+                    let dep_name = syn::Ident::new(&format!("__dep_{}", dep_index), field_name.span());
+                    dep_types.push(quote! { ::dependency_injector::verified::Dyn<#inner_type> });
+                    dep_names.push(dep_name.clone());
+                    field_inits.push(quote! { #field_name: #dep_name.0 });
+                    dep_index += 1;
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[dep(dyn)] must have type Arc<dyn Trait>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
+            Some(DepAttr::DynOptional) => {
+                // Field is Option<Arc<Trait>>; the Dependencies slot is
+                // Option<verified::Dyn<Trait>>.
+                if let Some(inner_type) = extract_option_arc_inner_type(field_type) {
+                    let dep_name = syn::Ident::new(&format!("__dep_{}", dep_index), field_name.span());
+                    dep_types.push(quote! { ::std::option::Option<::dependency_injector::verified::Dyn<#inner_type>> });
+                    dep_names.push(dep_name.clone());
+                    field_inits.push(quote! { #field_name: #dep_name.map(|d| d.0) });
+                    dep_index += 1;
+                } else {
+                    return syn::Error::new_spanned(
+                        field_type,
+                        "Fields marked with #[dep(optional, dyn)] must have type Option<Arc<dyn Trait>>"
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+            }
             None => {
                 // Non-dependency field - use Default
                 field_inits.push(quote! {
@@ -418,6 +1044,38 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
         }
     }
 
+    // `#[service(provides = dyn Trait)]` companion provider, binding the
+    // concrete type under each declared trait interface.
+    let provides = find_provides_attrs(&input.attrs);
+    let provider_name = syn::Ident::new(&format!("{}Provider", name), name.span());
+    let provider_binds = provides.iter().map(|trait_ty| {
+        quote! {
+            container.bind_interface::<#trait_ty, #name #ty_generics>(|concrete| concrete as ::std::sync::Arc<#trait_ty>);
+        }
+    });
+    let provider_def = if provides.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            /// Companion provider auto-generated from this struct's
+            /// `#[service(provides = dyn Trait)]` attributes. Binds the concrete
+            /// type under each declared trait interface via
+            /// `Container::bind_interface`, so the struct becomes resolvable
+            /// wherever one of those traits is requested (e.g. via
+            /// `#[dep(dyn)]`/`verified::Dyn`).
+            #[doc(hidden)]
+            pub struct #provider_name;
+
+            impl #provider_name {
+                /// Bind the concrete type under every trait interface declared
+                /// via `#[service(provides = ...)]`.
+                pub fn register(container: &::dependency_injector::Container) {
+                    #(#provider_binds)*
+                }
+            }
+        }
+    };
+
     // Generate the Dependencies type and create function
     let (deps_type, deps_pattern) = match dep_types.len() {
         0 => (quote! { () }, quote! { _ }),
@@ -435,6 +1093,8 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
 
     // Generate the implementation
     let expanded = quote! {
+        #(#marker_defs)*
+
         impl #impl_generics ::dependency_injector::verified::Service for #name #ty_generics #where_clause {
             type Dependencies = #deps_type;
 
@@ -444,6 +1104,8 @@ pub fn derive_service(input: TokenStream) -> TokenStream {
                 }
             }
         }
+
+        #provider_def
     };
 
     TokenStream::from(expanded)
@@ -0,0 +1,282 @@
+//! A validating builder that freezes into an immutable, pre-checked [`Container`].
+//!
+//! Unlike registering directly on a [`Container`], [`ContainerBuilder`] defers all
+//! registrations until [`ContainerBuilder::build`], at which point it validates the
+//! declared dependency graph once - catching missing providers and dependency cycles
+//! before anything is ever resolved, rather than panicking deep in a request handler.
+
+use std::any::{type_name, TypeId};
+use std::collections::HashMap;
+
+use crate::{CacheFactory, Container, DiError, Injectable, ProviderRegistration, Result};
+
+/// A dependency declared against a registration, used only for graph validation.
+#[derive(Clone, Copy)]
+struct DepRef {
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+struct Entry {
+    registration: ProviderRegistration,
+    dependencies: Vec<DepRef>,
+}
+
+/// Accumulates provider registrations and validates them as a whole before producing
+/// a [`Container`].
+///
+/// # Example
+///
+/// ```rust
+/// use dependency_injector::{provider, Container, ContainerBuilder};
+///
+/// #[derive(Clone)]
+/// struct Config { debug: bool }
+///
+/// #[derive(Clone)]
+/// struct Database { url: String }
+///
+/// let container = ContainerBuilder::new()
+///     .register(provider!(Config, Config { debug: true }))
+///     .register(provider!(Database, Database { url: "localhost".into() }))
+///     .depends_on::<Config>()
+///     .build()
+///     .unwrap();
+///
+/// assert!(container.contains::<Config>());
+/// assert!(container.contains::<Database>());
+/// assert!(container.is_locked());
+/// ```
+#[derive(Default)]
+pub struct ContainerBuilder {
+    entries: Vec<Entry>,
+    cache_factory: Option<Box<dyn CacheFactory>>,
+}
+
+impl ContainerBuilder {
+    /// Create a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Memoize the built container's resolutions in a cache minted by
+    /// `cache_factory` (see `Container::with_cache`), instead of the default
+    /// of keeping every resolved instance alive forever.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dependency_injector::{provider, ContainerBuilder, LruCacheFactory};
+    ///
+    /// #[derive(Clone)]
+    /// struct Config { debug: bool }
+    ///
+    /// let container = ContainerBuilder::new()
+    ///     .with_cache(LruCacheFactory::new(128))
+    ///     .register(provider!(Config, Config { debug: true }))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(container.contains::<Config>());
+    /// ```
+    pub fn with_cache(mut self, cache_factory: impl CacheFactory + 'static) -> Self {
+        self.cache_factory = Some(Box::new(cache_factory));
+        self
+    }
+
+    /// Register a provider. Returns `self` so dependencies can be attached with
+    /// [`ContainerBuilder::depends_on`].
+    pub fn register(mut self, registration: ProviderRegistration) -> Self {
+        self.entries.push(Entry {
+            registration,
+            dependencies: Vec::new(),
+        });
+        self
+    }
+
+    /// Declare that the most recently registered provider depends on `T`.
+    ///
+    /// Call this once per dependency, immediately after [`ContainerBuilder::register`].
+    /// Used only to validate the graph in [`ContainerBuilder::build`]; it does not
+    /// affect how `T` itself is constructed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before any provider has been registered.
+    pub fn depends_on<T: Injectable>(mut self) -> Self {
+        let dep = DepRef {
+            type_id: TypeId::of::<T>(),
+            type_name: type_name::<T>(),
+        };
+        self.entries
+            .last_mut()
+            .expect("depends_on() called before register()")
+            .dependencies
+            .push(dep);
+        self
+    }
+
+    /// Validate the declared dependency graph and build an immutable container.
+    ///
+    /// Fails if a declared dependency has no registered provider, or if the declared
+    /// dependencies form a cycle. On success, registrations run in dependency order
+    /// (a provider's dependencies are registered before the provider itself) and the
+    /// returned container is locked against further registration.
+    pub fn build(self) -> Result<Container> {
+        let index_by_type: HashMap<TypeId, usize> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (entry.registration.type_id, i))
+            .collect();
+
+        for entry in &self.entries {
+            for dep in &entry.dependencies {
+                if !index_by_type.contains_key(&dep.type_id) {
+                    return Err(DiError::graph_validation(format!(
+                        "no provider registered for `{}`, required by `{}`",
+                        dep.type_name, entry.registration.type_name
+                    )));
+                }
+            }
+        }
+
+        let order = topological_order(&self.entries, &index_by_type)?;
+
+        let container = match &self.cache_factory {
+            Some(cache_factory) => Container::with_cache(cache_factory.as_ref()),
+            None => Container::new(),
+        };
+        for index in order {
+            (self.entries[index].registration.register_fn)(&container);
+        }
+        container.lock();
+
+        Ok(container)
+    }
+}
+
+/// DFS-based topological sort with cycle detection over the declared dependency edges.
+fn topological_order(entries: &[Entry], index_by_type: &HashMap<TypeId, usize>) -> Result<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Mark {
+        Unvisited,
+        InProgress,
+        Done,
+    }
+
+    let mut marks = vec![Mark::Unvisited; entries.len()];
+    let mut order = Vec::with_capacity(entries.len());
+    let mut stack = Vec::new();
+
+    fn visit(
+        index: usize,
+        entries: &[Entry],
+        index_by_type: &HashMap<TypeId, usize>,
+        marks: &mut [Mark],
+        order: &mut Vec<usize>,
+        stack: &mut Vec<usize>,
+    ) -> Result<()> {
+        match marks[index] {
+            Mark::Done => return Ok(()),
+            Mark::InProgress => {
+                let cycle_start = stack.iter().position(|&i| i == index).unwrap_or(0);
+                let mut names: Vec<&str> = stack[cycle_start..]
+                    .iter()
+                    .map(|&i| entries[i].registration.type_name)
+                    .collect();
+                names.push(entries[index].registration.type_name);
+                return Err(DiError::graph_validation(format!(
+                    "circular dependency: {}",
+                    names.join(" -> ")
+                )));
+            }
+            Mark::Unvisited => {}
+        }
+
+        marks[index] = Mark::InProgress;
+        stack.push(index);
+
+        for dep in &entries[index].dependencies {
+            let dep_index = index_by_type[&dep.type_id];
+            visit(dep_index, entries, index_by_type, marks, order, stack)?;
+        }
+
+        stack.pop();
+        marks[index] = Mark::Done;
+        order.push(index);
+        Ok(())
+    }
+
+    for index in 0..entries.len() {
+        visit(index, entries, index_by_type, &mut marks, &mut order, &mut stack)?;
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider;
+
+    #[derive(Clone)]
+    struct Config {
+        debug: bool,
+    }
+
+    #[derive(Clone)]
+    struct Database {
+        url: String,
+    }
+
+    #[test]
+    fn test_build_validates_and_registers() {
+        let container = ContainerBuilder::new()
+            .register(provider!(Config, Config { debug: true }))
+            .register(provider!(Database, Database {
+                url: "localhost".into()
+            }))
+            .depends_on::<Config>()
+            .build()
+            .unwrap();
+
+        assert!(container.contains::<Config>());
+        assert!(container.contains::<Database>());
+        assert!(container.is_locked());
+    }
+
+    #[test]
+    fn test_build_fails_on_missing_provider() {
+        let result = ContainerBuilder::new()
+            .register(provider!(Database, Database {
+                url: "localhost".into()
+            }))
+            .depends_on::<Config>()
+            .build();
+
+        assert!(matches!(result, Err(DiError::GraphValidation { .. })));
+    }
+
+    #[test]
+    fn test_build_fails_on_cycle() {
+        #[derive(Clone)]
+        struct A;
+        #[derive(Clone)]
+        struct B;
+
+        let result = ContainerBuilder::new()
+            .register(provider!(A, A))
+            .depends_on::<B>()
+            .register(provider!(B, B))
+            .depends_on::<A>()
+            .build();
+
+        match result {
+            Err(DiError::GraphValidation { reason }) => {
+                assert!(reason.contains("circular dependency"));
+            }
+            other => panic!("expected GraphValidation error, got {other:?}"),
+        }
+    }
+}
@@ -3,6 +3,18 @@
 use std::any::TypeId;
 use thiserror::Error;
 
+/// Renders `CreationFailed`'s `path` as a trailing `" (while resolving: ...)"`
+/// clause, or an empty string when the failure happened with nothing else
+/// under construction - keeping the common case's message identical to
+/// before this field was added.
+fn format_path_suffix(path: &[&'static str]) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" (while resolving: {})", path.join(" -> "))
+    }
+}
+
 /// Errors that can occur during dependency injection operations
 #[derive(Error, Debug)]
 pub enum DiError {
@@ -13,15 +25,41 @@ pub enum DiError {
         type_id: TypeId,
     },
 
-    /// Circular dependency detected during resolution
-    #[error("Circular dependency detected while resolving: {type_name}")]
-    CircularDependency { type_name: &'static str },
+    /// No interface binding was registered for a trait object
+    #[error("No interface binding registered for: {type_name}")]
+    InterfaceNotFound { type_name: &'static str },
+
+    /// No `register_named` entry exists for the requested type under the
+    /// requested name - e.g. `#[inject(name = "primary")]` asked for a
+    /// binding that was only ever registered as `"replica"`, or never at all.
+    #[error("No named binding '{name}' registered for: {type_name}")]
+    NotFoundNamed {
+        type_name: &'static str,
+        name: &'static str,
+    },
 
-    /// Factory failed to create service
-    #[error("Failed to create service {type_name}: {reason}")]
+    /// `ContainerBuilder::build` found a problem with the declared dependency graph
+    #[error("Dependency graph validation failed: {reason}")]
+    GraphValidation { reason: String },
+
+    /// Circular dependency detected during resolution of a constructor-injected
+    /// factory (registered via `Container::factory`). `path` lists the types
+    /// in the order they were being constructed, with the type that closes
+    /// the cycle repeated at the end - mirrors `GraphError::Cycle`.
+    #[error("circular dependency detected while resolving: {}", .path.join(" -> "))]
+    CircularDependency { path: Vec<&'static str> },
+
+    /// Factory failed to create service. `path` lists the types under
+    /// construction above this one at the point of failure (outermost
+    /// first), the same chain `CircularDependency` reports - populated from
+    /// the active resolution stack, so a `construct_with`/`Service::create`
+    /// failure nested several levels deep still names the whole chain
+    /// instead of just the immediate type.
+    #[error("Failed to create service {type_name}: {reason}{}", format_path_suffix(.path))]
     CreationFailed {
         type_name: &'static str,
         reason: String,
+        path: Vec<&'static str>,
     },
 
     /// Container is locked and cannot be modified
@@ -36,6 +74,23 @@ pub enum DiError {
     #[error("Parent scope has been dropped")]
     ParentDropped,
 
+    /// Service was registered via `singleton_async`/`lazy_async`/`transient_async`
+    /// and can only be resolved through `Container::get_async`.
+    #[error("Service {type_name} is registered async-only - use Container::get_async instead of get")]
+    AsyncOnly { type_name: &'static str },
+
+    /// Service was registered via `try_lazy`/`try_transient` and can only be
+    /// resolved through `Container::try_resolve`, since `get` has no way to
+    /// surface the factory's `Err` case.
+    #[error("Service {type_name} is registered as a fallible factory - use Container::try_resolve instead of get")]
+    FallibleOnly { type_name: &'static str },
+
+    /// Service was registered via `Container::pooled`/`pooled_with_recycle`
+    /// and can only be checked out through `get_pooled`/`get_pooled_timeout`,
+    /// since `get` hands back a shared `Arc<T>`, not an exclusive checkout.
+    #[error("Service {type_name} is registered as a pool - use Container::get_pooled instead of get")]
+    PooledOnly { type_name: &'static str },
+
     /// Internal error
     #[error("Internal DI error: {0}")]
     Internal(String),
@@ -51,12 +106,41 @@ impl DiError {
         }
     }
 
-    /// Create a CreationFailed error
+    /// Create an InterfaceNotFound error for a trait object
+    #[inline]
+    pub fn interface_not_found<T: ?Sized + 'static>() -> Self {
+        Self::InterfaceNotFound {
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Create a NotFoundNamed error for a missing `register_named` entry
+    #[inline]
+    pub fn not_found_named<T: ?Sized + 'static>(name: &'static str) -> Self {
+        Self::NotFoundNamed {
+            type_name: std::any::type_name::<T>(),
+            name,
+        }
+    }
+
+    /// Create a GraphValidation error
+    #[inline]
+    pub fn graph_validation(reason: impl Into<String>) -> Self {
+        Self::GraphValidation {
+            reason: reason.into(),
+        }
+    }
+
+    /// Create a CreationFailed error, capturing the active resolution stack
+    /// (see `crate::container::current_resolution_path`) as its `path` so
+    /// the chain of types under construction above `T` is preserved even if
+    /// this error is used directly, outside a `ResolutionError`.
     #[inline]
     pub fn creation_failed<T: 'static>(reason: impl Into<String>) -> Self {
         Self::CreationFailed {
             type_name: std::any::type_name::<T>(),
             reason: reason.into(),
+            path: crate::container::current_resolution_path(),
         }
     }
 
@@ -68,10 +152,34 @@ impl DiError {
         }
     }
 
-    /// Create a CircularDependency error
+    /// Create a CircularDependency error from the active resolution stack,
+    /// with `type_name` appended to close the cycle.
     #[inline]
-    pub fn circular<T: 'static>() -> Self {
-        Self::CircularDependency {
+    pub fn circular(mut path: Vec<&'static str>, type_name: &'static str) -> Self {
+        path.push(type_name);
+        Self::CircularDependency { path }
+    }
+
+    /// Create an AsyncOnly error for a type registered via an async-only factory
+    #[inline]
+    pub fn async_only<T: 'static>() -> Self {
+        Self::AsyncOnly {
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Create a FallibleOnly error for a type registered via `try_lazy`/`try_transient`
+    #[inline]
+    pub fn fallible_only<T: 'static>() -> Self {
+        Self::FallibleOnly {
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Create a PooledOnly error for a type registered via `Container::pooled`
+    #[inline]
+    pub fn pooled_only<T: 'static>() -> Self {
+        Self::PooledOnly {
             type_name: std::any::type_name::<T>(),
         }
     }
@@ -84,18 +192,192 @@ impl Clone for DiError {
                 type_name,
                 type_id: *type_id,
             },
-            Self::CircularDependency { type_name } => Self::CircularDependency { type_name },
-            Self::CreationFailed { type_name, reason } => Self::CreationFailed {
+            Self::InterfaceNotFound { type_name } => Self::InterfaceNotFound { type_name },
+            Self::NotFoundNamed { type_name, name } => Self::NotFoundNamed { type_name, name },
+            Self::GraphValidation { reason } => Self::GraphValidation {
+                reason: reason.clone(),
+            },
+            Self::CircularDependency { path } => Self::CircularDependency { path: path.clone() },
+            Self::CreationFailed { type_name, reason, path } => Self::CreationFailed {
                 type_name,
                 reason: reason.clone(),
+                path: path.clone(),
             },
             Self::Locked => Self::Locked,
             Self::AlreadyRegistered { type_name } => Self::AlreadyRegistered { type_name },
             Self::ParentDropped => Self::ParentDropped,
+            Self::AsyncOnly { type_name } => Self::AsyncOnly { type_name },
+            Self::FallibleOnly { type_name } => Self::FallibleOnly { type_name },
+            Self::PooledOnly { type_name } => Self::PooledOnly { type_name },
             Self::Internal(s) => Self::Internal(s.clone()),
         }
     }
 }
 
+/// Error from `Container::verify()` - a problem with the dependency graph
+/// declared via `verified::ServiceProvider::provide`/`provide_transient`/etc.,
+/// found eagerly instead of panicking deep inside `get::<T>()`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GraphError {
+    /// A cycle was found. `path` lists the services involved in dependency
+    /// order, with the first entry repeated at the end to show where it closes.
+    #[error("circular dependency: {}", .0.join(" -> "))]
+    Cycle(Vec<&'static str>),
+
+    /// A declared, non-optional dependency has no registered provider.
+    #[error("no provider registered for `{missing}`, required by `{needed_by}`")]
+    MissingProvider {
+        needed_by: &'static str,
+        missing: &'static str,
+    },
+}
+
+/// Error from `Container::try_resolve` - distinguishes a resolution-machinery
+/// failure (no provider, async/autowired-only, a cycle) from a fallible
+/// factory's closure itself returning `Err`.
+///
+/// `syrette` keeps its whole resolution path `Result`-based; this crate's
+/// other factories panic on construction failure (the `#[derive(Inject)]`
+/// macro's generated closures do `.unwrap_or_else(|err| panic!(...))`), so
+/// `ResolveError` exists specifically for `try_lazy`/`try_transient`, which
+/// are the one place construction failure is expected to be recoverable.
+#[derive(Error, Debug)]
+pub enum ResolveError {
+    /// The usual `Container::get` resolution machinery failed - no provider
+    /// registered, an async-only or autowired-only mismatch, or a circular
+    /// dependency. Carries whatever `DiError` `get` would have returned.
+    #[error(transparent)]
+    Container(#[from] DiError),
+
+    /// A `try_lazy`/`try_transient` factory ran and its closure returned `Err`.
+    #[error("factory for {type_name} returned an error: {source}")]
+    Factory {
+        type_name: &'static str,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Error from resolving a `#[derive(Inject)]` dependency chain.
+///
+/// `DiError::NotFound` alone only names the *leaf* type that couldn't be
+/// resolved (e.g. `Cache`); it doesn't say which struct was being
+/// constructed when that lookup ran. `ResolutionError` wraps the leaf
+/// `DiError` with the chain of types under construction above it - read
+/// outermost first, e.g. `ApiController -> UserService -> Cache` - by
+/// reading the per-thread resolution stack `Container::get`'s callers push
+/// onto as nested `from_container` calls enter. Implements
+/// `std::error::Error` with a `source()` pointing at the leaf `DiError`, so
+/// it composes with `anyhow`/`thiserror` the same way `ResolveError` does.
+#[derive(Debug)]
+pub enum ResolutionError {
+    /// A leaf dependency could not be resolved. `path` lists the types
+    /// under construction above it (outermost first); `source` is whatever
+    /// `DiError` the failing lookup itself produced.
+    Missing {
+        path: Vec<&'static str>,
+        source: DiError,
+    },
+    /// A type appeared twice in the active `from_container` resolution
+    /// stack - two structs requiring each other (directly or transitively)
+    /// - so resolution stopped instead of recursing until stack overflow.
+    /// `path` lists the chain in construction order, with the type that
+    /// closes the cycle repeated at the end.
+    Cycle { path: Vec<&'static str> },
+}
+
+impl ResolutionError {
+    /// The chain of types under construction when this error occurred,
+    /// outermost first (for `Cycle`, the repeated closing type is included).
+    pub fn path(&self) -> &[&'static str] {
+        match self {
+            Self::Missing { path, .. } => path,
+            Self::Cycle { path } => path,
+        }
+    }
+}
+
+impl std::fmt::Display for ResolutionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { path, source } => {
+                if path.is_empty() {
+                    write!(f, "{source}")
+                } else {
+                    write!(f, "{} -> {source}", path.join(" -> "))
+                }
+            }
+            Self::Cycle { path } => {
+                write!(f, "circular dependency detected while resolving: {}", path.join(" -> "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ResolutionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Missing { source, .. } => Some(source),
+            Self::Cycle { .. } => None,
+        }
+    }
+}
+
+impl From<DiError> for ResolutionError {
+    /// Wraps `source` with whatever `from_container` chain is currently on
+    /// this thread's resolution stack - see `Container::current_resolution_path`.
+    fn from(source: DiError) -> Self {
+        Self::Missing {
+            path: crate::container::current_resolution_path(),
+            source,
+        }
+    }
+}
+
+impl From<GraphError> for ResolutionError {
+    /// Lets `Container::initialize_eager` report a cycle in the declared
+    /// graph through the same `ResolutionError` callers already handle for
+    /// per-service `on_init` failures, instead of a second error type. A
+    /// `MissingProvider` maps to `Missing`, naming the service that needed it.
+    fn from(err: GraphError) -> Self {
+        match err {
+            GraphError::Cycle(path) => Self::Cycle { path },
+            GraphError::MissingProvider { needed_by, missing } => Self::Missing {
+                path: vec![needed_by],
+                source: DiError::Internal(format!("no provider registered for `{missing}`")),
+            },
+        }
+    }
+}
+
+/// Error from `Container::warm_parallel` - one or more lazy singletons
+/// panicked while being eagerly initialized on the rayon thread pool.
+///
+/// Collecting lazy registrations to warm walks them by `TypeId` alone, not
+/// through a generic `T`, so there's no `&'static str` type name on hand the
+/// way other errors in this crate carry one - failures are keyed by
+/// `TypeId`, paired with whatever string could be recovered from the panic
+/// payload.
+#[derive(Debug)]
+pub struct WarmupError {
+    /// `(type_id, panic message)` for every lazy singleton whose factory panicked.
+    pub failures: Vec<(TypeId, String)>,
+}
+
+impl std::fmt::Display for WarmupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} lazy singleton(s) panicked during warm_parallel: ", self.failures.len())?;
+        for (i, (type_id, message)) in self.failures.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{type_id:?}: {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WarmupError {}
+
 /// Result type alias for DI operations
 pub type Result<T> = std::result::Result<T, DiError>;
@@ -9,14 +9,22 @@
 //! - Store type-erased `Arc<dyn Any>` directly to avoid clone+cast overhead
 //! - Enable better inlining opportunities
 
-use crate::Injectable;
+use crate::pool::PooledFactory;
+use crate::{Container, DiError, Injectable, Lifetime, ResolveError, Result};
+use arc_swap::ArcSwap;
 use once_cell::sync::OnceCell;
-use std::any::Any;
+use std::any::{Any, TypeId};
+use std::error::Error as StdError;
 use std::sync::Arc;
 
 #[cfg(feature = "logging")]
 use tracing::{debug, trace};
 
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
 /// A factory that creates service instances (trait for external extensibility)
 pub trait Factory: Send + Sync {
     /// Resolve the service, creating it if necessary
@@ -58,6 +66,15 @@ impl SingletonFactory {
         }
     }
 
+    /// Create from an already-erased instance.
+    ///
+    /// Used where the concrete type is only known at runtime, e.g.
+    /// `Container::build_from_config`'s config-driven registry builders.
+    #[inline]
+    pub fn from_any(instance: Arc<dyn Any + Send + Sync>) -> Self {
+        Self { instance }
+    }
+
     /// Resolve the instance (just clones the Arc, no cast needed)
     #[inline]
     pub fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
@@ -203,6 +220,379 @@ impl Factory for TransientFactory {
     }
 }
 
+// =============================================================================
+// Scoped Factory
+// =============================================================================
+
+/// Type-erased scoped factory function
+type ScopedInitFn = Arc<dyn Fn() -> Arc<dyn Any + Send + Sync> + Send + Sync>;
+
+/// Scoped factory - produces a fresh instance on demand, with no caching of
+/// its own.
+///
+/// Unlike `LazyFactory`, this factory may be reached through several
+/// different descendant scopes via the parent chain, and each of those
+/// scopes needs its own independent instance. Memoizing "once per scope"
+/// only makes sense one level up, against the specific scope doing the
+/// resolving (see `ServiceStorage::resolve_scoped`) - this type only knows
+/// how to produce a fresh instance.
+pub struct ScopedFactory {
+    /// Type-erased factory function
+    init: ScopedInitFn,
+}
+
+impl ScopedFactory {
+    /// Create a new scoped factory
+    #[inline]
+    pub fn new<T: Injectable, F>(factory: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            init: Arc::new(move || Arc::new(factory()) as Arc<dyn Any + Send + Sync>),
+        }
+    }
+
+    /// Create a fresh instance, bypassing any per-scope cache.
+    #[inline]
+    pub fn create(&self) -> Arc<dyn Any + Send + Sync> {
+        (self.init)()
+    }
+}
+
+impl Factory for ScopedFactory {
+    #[inline]
+    fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
+        self.create()
+    }
+}
+
+// =============================================================================
+// Reloadable Factory
+// =============================================================================
+
+/// Reloadable singleton factory - like `SingletonFactory`, but the instance
+/// can be atomically swapped for a new one at runtime via `replace`.
+///
+/// Backed by `ArcSwap` instead of storing a plain `Arc`: `resolve` is a
+/// wait-free `load_full()` and `replace` is a single atomic `store`, so
+/// concurrent reads never block on a reload and never observe a
+/// half-swapped value - each sees either the pre- or post-swap `Arc<T>` in
+/// full. This is exactly the guarantee arc-swap is built for, applied to
+/// "mostly read, occasionally replaced" state like config or feature flags.
+pub struct ReloadableFactory {
+    current: ArcSwap<dyn Any + Send + Sync>,
+}
+
+impl ReloadableFactory {
+    /// Create from an initial instance.
+    #[inline]
+    pub fn new<T: Injectable>(instance: T) -> Self {
+        Self {
+            current: ArcSwap::new(Arc::new(instance) as Arc<dyn Any + Send + Sync>),
+        }
+    }
+
+    /// Wait-free load of the current instance.
+    #[inline]
+    pub fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
+        self.current.load_full()
+    }
+
+    /// Atomically swap in a new instance. Readers already holding the old
+    /// `Arc` from a previous `resolve()` keep it - this only changes what
+    /// the *next* `resolve()` returns.
+    #[inline]
+    pub fn replace(&self, new: Arc<dyn Any + Send + Sync>) {
+        self.current.store(new);
+    }
+}
+
+impl Factory for ReloadableFactory {
+    #[inline]
+    fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
+        self.resolve()
+    }
+}
+
+// =============================================================================
+// Fallible Factories
+// =============================================================================
+
+/// Type-erased fallible factory function, boxing the caller's error type so
+/// `TryLazyFactory`/`TryTransientFactory` aren't generic over it.
+type TryInitFn = Arc<
+    dyn Fn() -> std::result::Result<Arc<dyn Any + Send + Sync>, Box<dyn StdError + Send + Sync>>
+        + Send
+        + Sync,
+>;
+
+/// Fallible lazy singleton factory - like `LazyFactory`, but the closure
+/// returns `Result<T, E>` instead of `T`.
+///
+/// Unlike `LazyFactory`'s `OnceCell::get_or_init`, a failed attempt must not
+/// be cached - a later resolve should retry construction rather than
+/// repeating the same failure forever. `OnceCell::get_or_try_init` already
+/// has exactly this behavior: the cell is only populated on `Ok`, so an `Err`
+/// leaves it empty for the next caller to try again.
+pub struct TryLazyFactory {
+    init: TryInitFn,
+    instance: OnceCell<Arc<dyn Any + Send + Sync>>,
+    type_name: &'static str,
+}
+
+impl TryLazyFactory {
+    /// Create a new fallible lazy factory
+    #[inline]
+    pub fn new<T: Injectable, E, F>(factory: F) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+        F: Fn() -> std::result::Result<T, E> + Send + Sync + 'static,
+    {
+        Self {
+            init: Arc::new(move || {
+                factory()
+                    .map(|v| Arc::new(v) as Arc<dyn Any + Send + Sync>)
+                    .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+            }),
+            instance: OnceCell::new(),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Get the instance, creating it if necessary. Does not cache a failed
+    /// attempt - the next call re-runs the factory.
+    #[inline]
+    pub fn resolve(&self) -> std::result::Result<Arc<dyn Any + Send + Sync>, ResolveError> {
+        if let Some(existing) = self.instance.get() {
+            return Ok(Arc::clone(existing));
+        }
+
+        self.instance
+            .get_or_try_init(|| (self.init)())
+            .map(Arc::clone)
+            .map_err(|source| ResolveError::Factory {
+                type_name: self.type_name,
+                source,
+            })
+    }
+}
+
+/// Fallible transient factory - like `TransientFactory`, but the closure
+/// returns `Result<T, E>` instead of `T`. No caching to speak of - every
+/// call re-runs the factory, success or failure.
+pub struct TryTransientFactory {
+    factory: TryInitFn,
+    type_name: &'static str,
+}
+
+impl TryTransientFactory {
+    /// Create a new fallible transient factory
+    #[inline]
+    pub fn new<T: Injectable, E, F>(factory: F) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+        F: Fn() -> std::result::Result<T, E> + Send + Sync + 'static,
+    {
+        Self {
+            factory: Arc::new(move || {
+                factory()
+                    .map(|v| Arc::new(v) as Arc<dyn Any + Send + Sync>)
+                    .map_err(|e| Box::new(e) as Box<dyn StdError + Send + Sync>)
+            }),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Create a new instance, propagating the factory's error if it fails.
+    #[inline]
+    pub fn create(&self) -> std::result::Result<Arc<dyn Any + Send + Sync>, ResolveError> {
+        (self.factory)().map_err(|source| ResolveError::Factory {
+            type_name: self.type_name,
+            source,
+        })
+    }
+}
+
+// =============================================================================
+// Autowired Factory
+// =============================================================================
+
+/// Type-erased autowiring factory function - receives the resolving
+/// `Container` so it can pull its own constructor arguments out of it.
+type AutowiredInitFn = Arc<dyn Fn(&Container) -> Arc<dyn Any + Send + Sync> + Send + Sync>;
+
+/// Autowired factory - creates a service by calling a closure that resolves
+/// its own dependencies from the `Container` (`Fn(&Container) -> T`), instead
+/// of requiring the caller to resolve and capture each dependency manually
+/// before registering a plain `lazy`/`transient` closure.
+///
+/// Caches its result after the first resolve, like `LazyFactory` - the common
+/// case for a constructor-injected service is "build the graph once, reuse
+/// it". Because the closure can recurse back into `Container::get` for its
+/// own dependencies, construction is tracked on a per-thread resolution
+/// stack (see `push_resolution` in `container.rs`) so a cycle aborts with a
+/// `DiError::CircularDependency` naming the chain instead of overflowing it.
+pub struct AutowiredFactory {
+    init: AutowiredInitFn,
+    instance: OnceCell<Arc<dyn Any + Send + Sync>>,
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+impl AutowiredFactory {
+    /// Create a new autowired factory
+    #[inline]
+    pub fn new<T: Injectable, F>(factory: F) -> Self
+    where
+        F: Fn(&Container) -> T + Send + Sync + 'static,
+    {
+        Self {
+            init: Arc::new(move |container| Arc::new(factory(container)) as Arc<dyn Any + Send + Sync>),
+            instance: OnceCell::new(),
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Resolve the instance, autowiring its constructor from `container` on
+    /// first access, then returning the cached instance on every call after.
+    ///
+    /// Pushes this factory's `TypeId` onto the per-thread resolution stack
+    /// *before* touching `self.instance`, so a dependency that (directly or
+    /// transitively) needs this same type back is caught by our own cycle
+    /// check instead of re-entering `OnceCell::get_or_try_init` while it's
+    /// still running this exact closure - `once_cell`'s own reentrant-init
+    /// guard is not something we want to rely on, and there's no path back
+    /// here where `self.instance` could already be set without having gone
+    /// through (and popped) the stack first.
+    pub fn resolve(&self, container: &Container) -> Result<Arc<dyn Any + Send + Sync>> {
+        if let Some(existing) = self.instance.get() {
+            return Ok(Arc::clone(existing));
+        }
+
+        let _guard = crate::container::push_resolution(self.type_id, self.type_name)?;
+
+        #[cfg(feature = "logging")]
+        debug!(
+            target: "dependency_injector",
+            service = self.type_name,
+            "Autowiring service from container"
+        );
+
+        self.instance
+            .get_or_try_init(|| Ok::<_, DiError>((self.init)(container)))
+            .map(Arc::clone)
+    }
+}
+
+// =============================================================================
+// Async Factory
+// =============================================================================
+
+/// Type-erased async factory function, returning a boxed future of the
+/// type-erased instance.
+#[cfg(feature = "async")]
+type AsyncInitFn =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Arc<dyn Any + Send + Sync>> + Send>> + Send + Sync>;
+
+/// How an [`AsyncFactory`]'s result is cached across `resolve` calls.
+#[cfg(feature = "async")]
+enum AsyncCaching {
+    /// Every resolve creates a fresh instance - mirrors `TransientFactory`.
+    Transient,
+    /// The first resolve's future is awaited once and cached for every call
+    /// after. Concurrent first-resolvers await the *same* in-flight future
+    /// rather than racing to construct their own instance, since
+    /// `tokio::sync::OnceCell::get_or_init` only polls its initializer once -
+    /// mirrors `LazyFactory`, but with an async-aware cell since the
+    /// synchronous `once_cell::sync::OnceCell` has no await-friendly
+    /// "in-flight" state to join.
+    Once(tokio::sync::OnceCell<Arc<dyn Any + Send + Sync>>),
+}
+
+/// Async factory - creates service instances via a `Future`.
+///
+/// Exists alongside the synchronous [`Factory`] variants for services whose
+/// construction needs to `.await` (opening a DB pool, fetching config over
+/// the network). Only resolved through `Container::get_async` - calling the
+/// synchronous `Container::get` on an async-only registration returns
+/// `DiError::AsyncOnly` rather than blocking or panicking.
+#[cfg(feature = "async")]
+pub struct AsyncFactory {
+    init: AsyncInitFn,
+    caching: AsyncCaching,
+    /// Type name for logging
+    #[cfg(feature = "logging")]
+    type_name: &'static str,
+}
+
+#[cfg(feature = "async")]
+impl AsyncFactory {
+    /// Create a cached async factory (backs both `singleton_async` and
+    /// `lazy_async` - see [`AsyncCaching::Once`] for why the two share an
+    /// implementation).
+    #[inline]
+    fn cached<T: Injectable, F, Fut>(factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            init: Arc::new(move || {
+                let fut = factory();
+                Box::pin(async move { Arc::new(fut.await) as Arc<dyn Any + Send + Sync> })
+            }),
+            caching: AsyncCaching::Once(tokio::sync::OnceCell::new()),
+            #[cfg(feature = "logging")]
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Create a transient async factory - a fresh future on every resolve.
+    #[inline]
+    fn transient<T: Injectable, F, Fut>(factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        Self {
+            init: Arc::new(move || {
+                let fut = factory();
+                Box::pin(async move { Arc::new(fut.await) as Arc<dyn Any + Send + Sync> })
+            }),
+            caching: AsyncCaching::Transient,
+            #[cfg(feature = "logging")]
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// Resolve the instance, awaiting the factory's future.
+    pub async fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
+        match &self.caching {
+            AsyncCaching::Transient => {
+                #[cfg(feature = "logging")]
+                trace!(
+                    target: "dependency_injector",
+                    service = self.type_name,
+                    "Creating new async transient instance"
+                );
+
+                (self.init)().await
+            }
+            AsyncCaching::Once(cell) => {
+                Arc::clone(cell.get_or_init(|| (self.init)()).await)
+            }
+        }
+    }
+
+    /// Check if this factory produces a new instance on every resolve.
+    #[inline]
+    fn is_transient(&self) -> bool {
+        matches!(self.caching, AsyncCaching::Transient)
+    }
+}
+
 // =============================================================================
 // AnyFactory - Enum-based type erasure (Phase 2 optimization)
 // =============================================================================
@@ -227,6 +617,34 @@ pub(crate) enum AnyFactory {
     Lazy(LazyFactory),
     /// Transient - new instance each time
     Transient(TransientFactory),
+    /// Scoped - at most one instance per child scope
+    Scoped(ScopedFactory),
+    /// Fallible lazy singleton - only resolvable via `Container::try_resolve`.
+    TryLazy(TryLazyFactory),
+    /// Fallible transient - only resolvable via `Container::try_resolve`.
+    TryTransient(TryTransientFactory),
+    /// Autowired - constructor-injected, resolves its own dependencies from
+    /// the `Container` at construction time. Wrapped in `Arc` so a caller
+    /// can clone it out of the `factories` map and invoke it without holding
+    /// the map's lock across the recursive `Container::get` calls its
+    /// closure makes.
+    Autowired(Arc<AutowiredFactory>),
+    /// Async - only resolvable via `Container::get_async`. Wrapped in `Arc`
+    /// so a caller can clone it out of the `factories` map and `.await` it
+    /// without holding the map's lock across the await point.
+    #[cfg(feature = "async")]
+    Async(Arc<AsyncFactory>),
+    /// Pooled - a bounded pool of instances, only resolvable via
+    /// `Container::get_pooled`/`get_pooled_timeout`. Wrapped in `Arc` for the
+    /// same reason as `Autowired`/`Async`: a caller needs to clone it out of
+    /// the `factories` map and check out/return instances without holding
+    /// the map's lock across that (potentially blocking) operation.
+    Pooled(Arc<PooledFactory>),
+    /// Reloadable - a singleton whose instance can be atomically swapped via
+    /// `Container::replace`. Wrapped in `Arc` so `Container::replace` can
+    /// clone it out of the `factories` map and call `replace` on it without
+    /// holding the map's lock across the swap.
+    Reloadable(Arc<ReloadableFactory>),
 }
 
 impl AnyFactory {
@@ -236,6 +654,12 @@ impl AnyFactory {
         AnyFactory::Singleton(SingletonFactory::new(instance))
     }
 
+    /// Create a singleton factory from an already type-erased instance.
+    #[inline]
+    pub fn singleton_from_any(instance: Arc<dyn Any + Send + Sync>) -> Self {
+        AnyFactory::Singleton(SingletonFactory::from_any(instance))
+    }
+
     /// Create a lazy factory
     #[inline]
     pub fn lazy<T: Injectable, F>(factory: F) -> Self
@@ -254,20 +678,309 @@ impl AnyFactory {
         AnyFactory::Transient(TransientFactory::new(factory))
     }
 
-    /// Resolve the service
+    /// Create a scoped factory
+    #[inline]
+    pub fn scoped<T: Injectable, F>(factory: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        AnyFactory::Scoped(ScopedFactory::new(factory))
+    }
+
+    /// Create a fallible lazy factory whose closure returns `Result<T, E>`.
+    #[inline]
+    pub fn try_lazy<T: Injectable, E, F>(factory: F) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+        F: Fn() -> std::result::Result<T, E> + Send + Sync + 'static,
+    {
+        AnyFactory::TryLazy(TryLazyFactory::new(factory))
+    }
+
+    /// Create a fallible transient factory whose closure returns `Result<T, E>`.
+    #[inline]
+    pub fn try_transient<T: Injectable, E, F>(factory: F) -> Self
+    where
+        E: StdError + Send + Sync + 'static,
+        F: Fn() -> std::result::Result<T, E> + Send + Sync + 'static,
+    {
+        AnyFactory::TryTransient(TryTransientFactory::new(factory))
+    }
+
+    /// Create an autowired factory whose closure resolves its own
+    /// dependencies from the `Container` passed to it.
+    #[inline]
+    pub fn autowired<T: Injectable, F>(factory: F) -> Self
+    where
+        F: Fn(&Container) -> T + Send + Sync + 'static,
+    {
+        AnyFactory::Autowired(Arc::new(AutowiredFactory::new(factory)))
+    }
+
+    /// Create an async factory whose result is cached after the first
+    /// `get_async` resolve (backs both `singleton_async` and `lazy_async` -
+    /// see [`AsyncCaching::Once`]).
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn lazy_async<T: Injectable, F, Fut>(factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        AnyFactory::Async(Arc::new(AsyncFactory::cached(factory)))
+    }
+
+    /// Create an async factory that produces a fresh instance on every
+    /// `get_async` resolve.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn transient_async<T: Injectable, F, Fut>(factory: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        AnyFactory::Async(Arc::new(AsyncFactory::transient(factory)))
+    }
+
+    /// Create a pooled factory whose idle instances are handed back out as-is.
+    #[inline]
+    pub fn pooled<T: Injectable, F>(factory: F, max_size: usize) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        AnyFactory::Pooled(Arc::new(PooledFactory::new(factory, max_size)))
+    }
+
+    /// Create a pooled factory whose idle instances are validated (and
+    /// possibly discarded) by `recycle` before being checked out again.
+    #[inline]
+    pub fn pooled_with_recycle<T: Injectable, F, R>(factory: F, max_size: usize, recycle: R) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+        R: Fn(&mut T) -> bool + Send + Sync + 'static,
+    {
+        AnyFactory::Pooled(Arc::new(PooledFactory::with_recycle(factory, max_size, recycle)))
+    }
+
+    /// Create a reloadable factory from an initial instance.
+    #[inline]
+    pub fn reloadable<T: Injectable>(instance: T) -> Self {
+        AnyFactory::Reloadable(Arc::new(ReloadableFactory::new(instance)))
+    }
+
+    /// Resolve the service.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a `TryLazy`/`TryTransient`, `Autowired`, `Async`, or
+    /// `Pooled` factory - the fallible variants need a way to report `Err`
+    /// that this signature can't provide, `Autowired`/`Async` need a
+    /// `&Container` (and, for `Async`, an `.await`), and `Pooled` hands back
+    /// an exclusive checkout rather than a shared `Arc`. Every call site
+    /// reachable from `Container::get` checks `is_fallible`/`is_autowired`/
+    /// `is_async`/`is_pooled` first and routes those registrations around
+    /// this method instead of reaching here.
     #[inline]
     pub fn resolve(&self) -> Arc<dyn Any + Send + Sync> {
         match self {
             AnyFactory::Singleton(f) => f.resolve(),
             AnyFactory::Lazy(f) => f.resolve(),
             AnyFactory::Transient(f) => f.create(),
+            AnyFactory::Scoped(f) => f.create(),
+            AnyFactory::Reloadable(f) => f.resolve(),
+            AnyFactory::TryLazy(_) | AnyFactory::TryTransient(_) => {
+                unreachable!("AnyFactory::TryLazy/TryTransient must be resolved via Container::try_resolve")
+            }
+            AnyFactory::Autowired(_) => {
+                unreachable!("AnyFactory::Autowired must be resolved via Container::get, which passes itself to the closure")
+            }
+            #[cfg(feature = "async")]
+            AnyFactory::Async(_) => {
+                unreachable!("AnyFactory::Async must be resolved via Container::get_async")
+            }
+            AnyFactory::Pooled(_) => {
+                unreachable!("AnyFactory::Pooled must be resolved via Container::get_pooled")
+            }
+        }
+    }
+
+    /// If this factory is `TryLazy`/`TryTransient`, resolve it, surfacing
+    /// either case distinctly through `ResolveError`. Returns `None` for
+    /// every other lifetime, so callers can tell "not fallible" apart from
+    /// "fallible, and here's the result" without a separate check.
+    #[inline]
+    pub fn try_resolve(&self) -> Option<std::result::Result<Arc<dyn Any + Send + Sync>, ResolveError>> {
+        match self {
+            AnyFactory::TryLazy(f) => Some(f.resolve()),
+            AnyFactory::TryTransient(f) => Some(f.create()),
+            _ => None,
+        }
+    }
+
+    /// Check if this factory is fallible (resolvable only via `try_resolve`).
+    #[inline]
+    pub fn is_fallible(&self) -> bool {
+        matches!(self, AnyFactory::TryLazy(_) | AnyFactory::TryTransient(_))
+    }
+
+    /// If this factory is `Autowired`, clone out the `Arc<AutowiredFactory>`
+    /// so the caller can resolve it with a `&Container` without holding a
+    /// reference into the `factories` map across the closure's own recursive
+    /// `Container::get` calls. Returns `None` for every other lifetime.
+    #[inline]
+    pub fn autowired_factory(&self) -> Option<Arc<AutowiredFactory>> {
+        match self {
+            AnyFactory::Autowired(f) => Some(Arc::clone(f)),
+            _ => None,
+        }
+    }
+
+    /// Check if this factory is autowired (resolvable only with a `&Container`).
+    #[inline]
+    pub fn is_autowired(&self) -> bool {
+        matches!(self, AnyFactory::Autowired(_))
+    }
+
+    /// If this factory is `Async`, clone out the `Arc<AsyncFactory>` so the
+    /// caller can `.await` its `resolve()` without holding a reference into
+    /// the `factories` map across the await point. Returns `None` for every
+    /// other lifetime.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn async_factory(&self) -> Option<Arc<AsyncFactory>> {
+        match self {
+            AnyFactory::Async(f) => Some(Arc::clone(f)),
+            _ => None,
+        }
+    }
+
+    /// Check if this factory is async-only (resolvable only via `get_async`).
+    #[inline]
+    pub fn is_async(&self) -> bool {
+        #[cfg(feature = "async")]
+        {
+            matches!(self, AnyFactory::Async(_))
+        }
+        #[cfg(not(feature = "async"))]
+        {
+            false
+        }
+    }
+
+    /// If this factory is `Pooled`, clone out the `Arc<PooledFactory>` so the
+    /// caller can check out (and later check back in) an instance without
+    /// holding a reference into the `factories` map across a potentially
+    /// blocking checkout. Returns `None` for every other lifetime.
+    #[inline]
+    pub fn pooled_factory(&self) -> Option<Arc<PooledFactory>> {
+        match self {
+            AnyFactory::Pooled(f) => Some(Arc::clone(f)),
+            _ => None,
+        }
+    }
+
+    /// Check if this factory is pooled (resolvable only via `get_pooled`/
+    /// `get_pooled_timeout`).
+    #[inline]
+    pub fn is_pooled(&self) -> bool {
+        matches!(self, AnyFactory::Pooled(_))
+    }
+
+    /// If this factory is `Reloadable`, clone out the `Arc<ReloadableFactory>`
+    /// so `Container::replace` can swap its instance without holding a
+    /// reference into the `factories` map. Returns `None` for every other
+    /// lifetime.
+    #[inline]
+    pub fn reloadable_factory(&self) -> Option<Arc<ReloadableFactory>> {
+        match self {
+            AnyFactory::Reloadable(f) => Some(Arc::clone(f)),
+            _ => None,
+        }
+    }
+
+    /// If this factory is `Scoped`, produce a fresh instance - bypassing any
+    /// per-scope cache the caller maintains. Returns `None` for every other
+    /// lifetime, so callers can tell "not scoped" apart from "scoped, and
+    /// here's a fresh instance" without a separate lifetime check.
+    #[inline]
+    pub fn create_scoped(&self) -> Option<Arc<dyn Any + Send + Sync>> {
+        match self {
+            AnyFactory::Scoped(f) => Some(f.create()),
+            _ => None,
         }
     }
 
     /// Check if transient
     #[inline]
     pub fn is_transient(&self) -> bool {
-        matches!(self, AnyFactory::Transient(_))
+        match self {
+            AnyFactory::Transient(_) => true,
+            AnyFactory::TryTransient(_) => true,
+            #[cfg(feature = "async")]
+            AnyFactory::Async(f) => f.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// The `Lifetime` this factory was registered with.
+    ///
+    /// Autowired and async registrations report `Lifetime::Lazy` for metrics
+    /// purposes, since there is no dedicated `Lifetime` variant for them and
+    /// both share the same "created on first access, then cached" shape
+    /// (except for `transient_async`, which is still reported as `Lazy` here
+    /// - metrics granularity for async factories is coarser than for sync
+    /// ones).
+    #[inline]
+    pub fn lifetime(&self) -> Lifetime {
+        match self {
+            AnyFactory::Singleton(_) => Lifetime::Singleton,
+            AnyFactory::Lazy(_) => Lifetime::Lazy,
+            AnyFactory::Transient(_) => Lifetime::Transient,
+            AnyFactory::Scoped(_) => Lifetime::Scoped,
+            AnyFactory::TryLazy(_) => Lifetime::Lazy,
+            AnyFactory::TryTransient(_) => Lifetime::Transient,
+            AnyFactory::Autowired(_) => Lifetime::Lazy,
+            #[cfg(feature = "async")]
+            AnyFactory::Async(_) => Lifetime::Lazy,
+            AnyFactory::Pooled(_) => Lifetime::Pooled,
+            AnyFactory::Reloadable(_) => Lifetime::Reloadable,
+        }
+    }
+
+    /// Strong count of whatever instance is currently cached by this
+    /// factory, or `0` if nothing's been created yet (a lazy/autowired
+    /// factory that's never fired) or this lifetime doesn't cache an
+    /// instance of its own at all (`Transient`, `TryTransient`, `Pooled`,
+    /// `Async`, `Scoped` - the latter caches per-*calling*-scope, not here).
+    ///
+    /// `ScopePool::release` uses this to tell whether a caller is still
+    /// holding an `Arc<T>` clone resolved from this storage (e.g. captured
+    /// by spawned background work) beyond the synchronous call that
+    /// acquired the scope - every `Arc<T>` handed out is a clone of the
+    /// exact `Arc` counted here, so its strong count is the
+    /// outstanding-reference count, with no separate checkout guard needed.
+    /// `HotCache` entries hold clones too: `release` drops the releasing
+    /// thread's entries before checking, while other threads' entries still
+    /// count.
+    #[inline]
+    pub(crate) fn cached_strong_count(&self) -> usize {
+        match self {
+            AnyFactory::Singleton(f) => Arc::strong_count(&f.instance),
+            AnyFactory::Lazy(f) => f.instance.get().map(Arc::strong_count).unwrap_or(0),
+            AnyFactory::TryLazy(f) => f.instance.get().map(Arc::strong_count).unwrap_or(0),
+            AnyFactory::Autowired(f) => f.instance.get().map(Arc::strong_count).unwrap_or(0),
+            AnyFactory::Reloadable(f) => {
+                // `load_full` clones the `Arc` to hand it back, so subtract
+                // that temporary reference back out of the count.
+                Arc::strong_count(&f.current.load_full()) - 1
+            }
+            AnyFactory::Transient(_)
+            | AnyFactory::TryTransient(_)
+            | AnyFactory::Scoped(_)
+            | AnyFactory::Pooled(_) => 0,
+            #[cfg(feature = "async")]
+            AnyFactory::Async(_) => 0,
+        }
     }
 }
 
@@ -330,14 +1043,188 @@ mod tests {
         assert!(!Arc::ptr_eq(&a, &b));
     }
 
+    #[test]
+    fn test_scoped_factory_always_creates_fresh() {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        let factory = AnyFactory::scoped(|| TestService {
+            id: COUNTER.fetch_add(1, Ordering::SeqCst),
+        });
+
+        // Unlike Lazy, a ScopedFactory never caches - per-scope memoization
+        // happens in `ServiceStorage`, not here.
+        let a = factory.resolve().downcast::<TestService>().unwrap();
+        let b = factory.resolve().downcast::<TestService>().unwrap();
+
+        assert_eq!(a.id, 0);
+        assert_eq!(b.id, 1);
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_autowired_factory_resolves_and_caches() {
+        use crate::Container;
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        let container = Container::new();
+        let factory = AnyFactory::autowired(|_c: &Container| TestService {
+            id: COUNTER.fetch_add(1, Ordering::SeqCst),
+        });
+
+        let a = match &factory {
+            AnyFactory::Autowired(f) => f.resolve(&container).unwrap(),
+            _ => unreachable!(),
+        };
+        let b = match &factory {
+            AnyFactory::Autowired(f) => f.resolve(&container).unwrap(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_create_scoped() {
+        let scoped = AnyFactory::scoped(|| TestService { id: 1 });
+        let singleton = AnyFactory::singleton(TestService { id: 2 });
+
+        assert!(scoped.create_scoped().is_some());
+        assert!(singleton.create_scoped().is_none());
+    }
+
     #[test]
     fn test_is_transient() {
+        use crate::Container;
+
         let singleton = AnyFactory::singleton(TestService { id: 1 });
         let lazy = AnyFactory::lazy(|| TestService { id: 2 });
         let transient = AnyFactory::transient(|| TestService { id: 3 });
+        let scoped = AnyFactory::scoped(|| TestService { id: 4 });
+        let autowired = AnyFactory::autowired(|_c: &Container| TestService { id: 5 });
+        let try_lazy = AnyFactory::try_lazy(|| "6".parse::<u32>().map(|id| TestService { id }));
+        let try_transient = AnyFactory::try_transient(|| "7".parse::<u32>().map(|id| TestService { id }));
 
         assert!(!singleton.is_transient());
         assert!(!lazy.is_transient());
         assert!(transient.is_transient());
+        assert!(!scoped.is_transient());
+        assert!(!autowired.is_transient());
+        assert!(!try_lazy.is_transient());
+        assert!(try_transient.is_transient());
+    }
+
+    #[test]
+    fn test_lifetime() {
+        use crate::Container;
+
+        let singleton = AnyFactory::singleton(TestService { id: 1 });
+        let lazy = AnyFactory::lazy(|| TestService { id: 2 });
+        let transient = AnyFactory::transient(|| TestService { id: 3 });
+        let scoped = AnyFactory::scoped(|| TestService { id: 4 });
+        let autowired = AnyFactory::autowired(|_c: &Container| TestService { id: 5 });
+        let try_lazy = AnyFactory::try_lazy(|| "6".parse::<u32>().map(|id| TestService { id }));
+        let try_transient = AnyFactory::try_transient(|| "7".parse::<u32>().map(|id| TestService { id }));
+
+        assert_eq!(singleton.lifetime(), Lifetime::Singleton);
+        assert_eq!(lazy.lifetime(), Lifetime::Lazy);
+        assert_eq!(transient.lifetime(), Lifetime::Transient);
+        assert_eq!(scoped.lifetime(), Lifetime::Scoped);
+        assert_eq!(autowired.lifetime(), Lifetime::Lazy);
+        assert_eq!(try_lazy.lifetime(), Lifetime::Lazy);
+        assert_eq!(try_transient.lifetime(), Lifetime::Transient);
+    }
+
+    #[test]
+    fn test_try_lazy_factory_caches_on_success() {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        let factory = AnyFactory::try_lazy(|| {
+            COUNTER.fetch_add(1, Ordering::SeqCst);
+            "42".parse::<u32>().map(|id| TestService { id })
+        });
+
+        let a = factory.try_resolve().unwrap().unwrap().downcast::<TestService>().unwrap();
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+        assert_eq!(a.id, 42);
+
+        let b = factory.try_resolve().unwrap().unwrap().downcast::<TestService>().unwrap();
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+        assert!(Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_try_lazy_factory_does_not_cache_failure() {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        let factory = AnyFactory::try_lazy(|| {
+            let attempt = COUNTER.fetch_add(1, Ordering::SeqCst);
+            if attempt == 0 {
+                "not a number".parse::<u32>().map(|id| TestService { id })
+            } else {
+                "99".parse::<u32>().map(|id| TestService { id })
+            }
+        });
+
+        assert!(matches!(
+            factory.try_resolve().unwrap(),
+            Err(crate::ResolveError::Factory { .. })
+        ));
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+
+        // A failed attempt isn't cached - the retry actually re-runs the factory.
+        let resolved = factory.try_resolve().unwrap().unwrap().downcast::<TestService>().unwrap();
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 2);
+        assert_eq!(resolved.id, 99);
+    }
+
+    #[test]
+    fn test_try_transient_factory_never_caches() {
+        let factory = AnyFactory::try_transient(|| "1".parse::<u32>().map(|id| TestService { id }));
+
+        let a = factory.try_resolve().unwrap().unwrap().downcast::<TestService>().unwrap();
+        let b = factory.try_resolve().unwrap().unwrap().downcast::<TestService>().unwrap();
+
+        assert!(!Arc::ptr_eq(&a, &b));
+    }
+
+    #[test]
+    fn test_try_resolve_returns_none_for_non_fallible_factories() {
+        let singleton = AnyFactory::singleton(TestService { id: 1 });
+        assert!(singleton.try_resolve().is_none());
+    }
+
+    #[test]
+    fn test_reloadable_factory_resolves_and_replaces() {
+        let factory = AnyFactory::reloadable(TestService { id: 1 });
+
+        let a = factory.resolve().downcast::<TestService>().unwrap();
+        assert_eq!(a.id, 1);
+
+        let handle = factory.reloadable_factory().unwrap();
+        handle.replace(Arc::new(TestService { id: 2 }));
+
+        let b = factory.resolve().downcast::<TestService>().unwrap();
+        assert_eq!(b.id, 2);
+        assert!(!Arc::ptr_eq(&a, &b));
+
+        // The reader that resolved before the swap keeps its own `Arc` -
+        // replacing never mutates an already-handed-out instance.
+        assert_eq!(a.id, 1);
+    }
+
+    #[test]
+    fn test_reloadable_factory_is_not_transient_and_lifetime_is_reloadable() {
+        let factory = AnyFactory::reloadable(TestService { id: 1 });
+
+        assert!(!factory.is_transient());
+        assert_eq!(factory.lifetime(), Lifetime::Reloadable);
+    }
+
+    #[test]
+    fn test_reloadable_factory_accessor_returns_none_for_other_lifetimes() {
+        let singleton = AnyFactory::singleton(TestService { id: 1 });
+        assert!(singleton.reloadable_factory().is_none());
     }
 }
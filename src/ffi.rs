@@ -14,22 +14,255 @@
 //! - `di_container_new()` allocates a container - must be freed with `di_container_free()`
 //! - `di_service_*` functions return service handles - must be freed with `di_service_free()`
 //! - `di_error_message()` returns a string - must be freed with `di_string_free()`
+//! - `di_last_error()` fills a `DiErrorInfo` whose `message` must be freed
+//!   with `di_string_free()`; its `class` is static and must NOT be freed
 //!
 //! # Thread Safety
 //!
 //! The container is thread-safe. All FFI functions can be called from multiple threads.
 
-use std::any::Any;
+use once_cell::sync::OnceCell;
 use std::collections::HashMap;
-use std::ffi::{CStr, CString, c_char};
+use std::ffi::{CStr, CString, c_char, c_void};
 use std::ptr;
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 
 /// Opaque container handle for FFI
 pub struct DiContainer {
     inner: crate::Container,
-    /// Map of type names to their registered services (as raw bytes)
-    services: RwLock<HashMap<String, Arc<dyn Any + Send + Sync>>>,
+    /// Map of type names to their registered services.
+    ///
+    /// `Arc`-wrapped (rather than owned directly) so `di_container_scope` can
+    /// share one entry - and, for `ServiceEntry::Factory`, one destructor
+    /// run - between a parent and every child scope that inherits it,
+    /// instead of needing `ServiceEntry` to be `Clone`.
+    services: RwLock<HashMap<String, Arc<ServiceEntry>>>,
+}
+
+/// C callback invoked by `di_resolve`/`di_resolve_json` to produce a
+/// factory-registered service's bytes on demand.
+///
+/// Writes an owned buffer's pointer and length to `out_data`/`out_len` and
+/// returns `DiErrorCode::Ok` on success. The bytes are copied into Rust
+/// immediately after the call returns, then `*out_data` is handed to the
+/// `free_result` callback supplied alongside this one at registration (see
+/// `di_register_factory`) - the buffer must be allocated in whatever way
+/// that `free_result` knows how to release.
+pub type DiFactoryCallback =
+    extern "C" fn(user_data: *mut c_void, out_data: *mut *mut u8, out_len: *mut usize) -> DiErrorCode;
+
+/// Paired with a `DiFactoryCallback`/`DiCodecFn` at registration to release
+/// the buffer it wrote to `out_data`/`out_len`, right after this module
+/// copies it into an owned `Vec`. Not invoked when `len` is `0`.
+pub type DiFreeResultFn = extern "C" fn(data: *mut u8, len: usize);
+
+/// How often a `di_register_factory` callback is invoked on resolve.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiFactoryLifetime {
+    /// Invoke the callback fresh on every `di_resolve`/`di_resolve_json`.
+    Transient = 0,
+    /// Invoke the callback once, then serve the cached bytes for the
+    /// lifetime of the registration (a `di_container_scope` child inherits
+    /// the entry and shares the same cache, since it shares the same
+    /// `Arc<ServiceEntry>`).
+    Scoped = 1,
+}
+
+/// The opaque `user_data` pointer and its optional destructor.
+///
+/// Neither field is automatically `Send + Sync` - callers can only obtain
+/// one of these by asserting thread-safety via `di_register_factory`'s
+/// `thread_safe` flag, which is the one place this type is constructed.
+struct FfiCallbackHandle {
+    user_data: *mut c_void,
+    free_user_data: Option<extern "C" fn(*mut c_void)>,
+}
+
+// SAFETY: `di_register_factory` refuses to construct a `FfiCallbackHandle`
+// unless its caller passes a non-zero `thread_safe`, asserting that
+// `user_data` is actually safe to access from whatever thread ends up
+// calling the resolve functions.
+unsafe impl Send for FfiCallbackHandle {}
+unsafe impl Sync for FfiCallbackHandle {}
+
+impl Drop for FfiCallbackHandle {
+    /// Run the caller-supplied destructor, if any, when the last
+    /// `Arc<ServiceEntry>` referencing this handle (see `DiContainer::services`)
+    /// goes away - e.g. `di_container_free` dropping the last container that
+    /// held it.
+    fn drop(&mut self) {
+        if let Some(free) = self.free_user_data {
+            free(self.user_data);
+        }
+    }
+}
+
+/// A single registered service: either eager bytes, or a callback that
+/// produces them on demand.
+enum ServiceEntry {
+    /// Bytes provided up front via `di_register_singleton`/`_json`.
+    Singleton(Vec<u8>),
+    /// A `di_register_factory` registration.
+    Factory {
+        callback: DiFactoryCallback,
+        /// Released on the buffer `callback` writes to `out_data`/`out_len`,
+        /// right after it's copied into Rust - see `DiFreeResultFn`.
+        free_result: DiFreeResultFn,
+        handle: FfiCallbackHandle,
+        lifetime: DiFactoryLifetime,
+        /// Populated on first resolve when `lifetime` is `Scoped`; always
+        /// empty (and never consulted) for `Transient`.
+        cached: Mutex<Option<Vec<u8>>>,
+    },
+}
+
+/// Resolve `entry` to its current bytes, invoking (and for `Scoped`,
+/// memoizing) the callback for a `Factory` entry.
+fn resolve_service_bytes(entry: &ServiceEntry) -> Result<Vec<u8>, DiErrorCode> {
+    match entry {
+        ServiceEntry::Singleton(data) => Ok(data.clone()),
+        ServiceEntry::Factory {
+            callback,
+            free_result,
+            handle,
+            lifetime,
+            cached,
+        } => {
+            if *lifetime == DiFactoryLifetime::Scoped {
+                if let Some(bytes) = cached.lock().unwrap().as_ref() {
+                    return Ok(bytes.clone());
+                }
+            }
+
+            let mut out_data: *mut u8 = ptr::null_mut();
+            let mut out_len: usize = 0;
+            let code = callback(handle.user_data, &mut out_data, &mut out_len);
+            if code != DiErrorCode::Ok {
+                return Err(code);
+            }
+
+            // SAFETY: the callback reported success, so it guarantees
+            // out_data/out_len describe a valid, readable buffer.
+            let bytes = if out_len > 0 && !out_data.is_null() {
+                let copied = unsafe { std::slice::from_raw_parts(out_data, out_len) }.to_vec();
+                free_result(out_data, out_len);
+                copied
+            } else {
+                Vec::new()
+            };
+
+            if *lifetime == DiFactoryLifetime::Scoped {
+                *cached.lock().unwrap() = Some(bytes.clone());
+            }
+
+            Ok(bytes)
+        }
+    }
+}
+
+/// C callback that transforms bytes for a registered codec - either the
+/// `encode` half (caller-supplied data -> bytes to store) or the `decode`
+/// half (stored bytes -> bytes to hand back to the caller).
+///
+/// Writes an owned buffer's pointer and length to `out_data`/`out_len` and
+/// returns `DiErrorCode::Ok` on success, mirroring `DiFactoryCallback` - the
+/// bytes are copied into Rust immediately after the call returns, then
+/// `*out_data` is handed to the matching `DiFreeResultFn` supplied alongside
+/// this one at registration (see `di_register_codec`).
+pub type DiCodecFn =
+    extern "C" fn(data: *const u8, len: usize, out_data: *mut *mut u8, out_len: *mut usize) -> DiErrorCode;
+
+/// A named pair of encode/decode transforms, shared across every container.
+struct Codec {
+    encode: DiCodecFn,
+    /// Released on the buffer `encode` writes to `out_data`/`out_len`.
+    encode_free: DiFreeResultFn,
+    decode: DiCodecFn,
+    /// Released on the buffer `decode` writes to `out_data`/`out_len`.
+    decode_free: DiFreeResultFn,
+}
+
+/// Process-global codec registry, shared across every `DiContainer` so a
+/// codec registered once (e.g. by a language wrapper's init routine) is
+/// available everywhere.
+static CODECS: OnceCell<RwLock<HashMap<String, Codec>>> = OnceCell::new();
+
+/// `"json"`'s encode/decode are both identity copies: JSON services are
+/// already stored as their UTF-8 bytes by `di_register_singleton_json`, so
+/// routing them through the registry changes nothing observable.
+extern "C" fn json_codec_passthrough(
+    data: *const u8,
+    len: usize,
+    out_data: *mut *mut u8,
+    out_len: *mut usize,
+) -> DiErrorCode {
+    let bytes = if len > 0 {
+        // SAFETY: Caller guarantees data points to len bytes
+        unsafe { std::slice::from_raw_parts(data, len) }.to_vec()
+    } else {
+        Vec::new()
+    };
+    let boxed = bytes.into_boxed_slice();
+    let len = boxed.len();
+    let ptr = Box::into_raw(boxed) as *mut u8;
+    // SAFETY: out_data/out_len are valid per this function's contract
+    unsafe {
+        *out_data = ptr;
+        *out_len = len;
+    }
+    DiErrorCode::Ok
+}
+
+/// Paired `DiFreeResultFn` for `json_codec_passthrough`'s buffers - reclaims
+/// the boxed slice it allocated via `Box::into_raw`.
+extern "C" fn json_codec_passthrough_free(data: *mut u8, len: usize) {
+    if data.is_null() {
+        return;
+    }
+    // SAFETY: `json_codec_passthrough` is the only producer of buffers freed
+    // through this function, and it always allocates via
+    // `Vec::into_boxed_slice`/`Box::into_raw` with this exact `len`.
+    drop(unsafe { Box::from_raw(std::slice::from_raw_parts_mut(data, len) as *mut [u8]) });
+}
+
+fn codecs() -> &'static RwLock<HashMap<String, Codec>> {
+    CODECS.get_or_init(|| {
+        let mut registry = HashMap::new();
+        registry.insert(
+            "json".to_string(),
+            Codec {
+                encode: json_codec_passthrough,
+                encode_free: json_codec_passthrough_free,
+                decode: json_codec_passthrough,
+                decode_free: json_codec_passthrough_free,
+            },
+        );
+        RwLock::new(registry)
+    })
+}
+
+/// Invoke a `DiCodecFn`, copy its output into an owned `Vec`, then release
+/// the original buffer via `free_result`.
+fn invoke_codec(f: DiCodecFn, free_result: DiFreeResultFn, data: &[u8]) -> Result<Vec<u8>, DiErrorCode> {
+    let mut out_data: *mut u8 = ptr::null_mut();
+    let mut out_len: usize = 0;
+    let code = f(data.as_ptr(), data.len(), &mut out_data, &mut out_len);
+    if code != DiErrorCode::Ok {
+        return Err(code);
+    }
+
+    // SAFETY: the codec reported success, so it guarantees out_data/out_len
+    // describe a valid, readable buffer.
+    let bytes = if out_len > 0 && !out_data.is_null() {
+        let copied = unsafe { std::slice::from_raw_parts(out_data, out_len) }.to_vec();
+        free_result(out_data, out_len);
+        copied
+    } else {
+        Vec::new()
+    };
+
+    Ok(bytes)
 }
 
 /// Opaque service handle for FFI
@@ -63,17 +296,33 @@ pub struct DiResult {
     pub service: *mut DiService,
 }
 
-// Thread-local storage for the last error message
+// Thread-local storage for the last error code and message
 thread_local! {
-    static LAST_ERROR: std::cell::RefCell<Option<String>> = const { std::cell::RefCell::new(None) };
+    static LAST_ERROR: std::cell::RefCell<Option<(DiErrorCode, String)>> =
+        const { std::cell::RefCell::new(None) };
 }
 
-fn set_last_error(msg: impl Into<String>) {
+fn set_last_error(code: DiErrorCode, msg: impl Into<String>) {
     LAST_ERROR.with(|e| {
-        *e.borrow_mut() = Some(msg.into());
+        *e.borrow_mut() = Some((code, msg.into()));
     });
 }
 
+/// Map an error code to its stable, statically-allocated class slug.
+///
+/// These slugs are part of the FFI contract: callers map them to their own
+/// exception types, so once published a slug must never change meaning.
+fn error_class(code: DiErrorCode) -> &'static CStr {
+    match code {
+        DiErrorCode::Ok => c"Ok",
+        DiErrorCode::NotFound => c"NotFound",
+        DiErrorCode::InvalidArgument => c"InvalidArgument",
+        DiErrorCode::AlreadyRegistered => c"AlreadyRegistered",
+        DiErrorCode::InternalError => c"InternalError",
+        DiErrorCode::SerializationError => c"SerializationError",
+    }
+}
+
 // ============================================================================
 // Container Lifecycle
 // ============================================================================
@@ -118,7 +367,7 @@ pub unsafe extern "C" fn di_container_free(container: *mut DiContainer) {
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn di_container_scope(container: *mut DiContainer) -> *mut DiContainer {
     if container.is_null() {
-        set_last_error("Container pointer is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
         return ptr::null_mut();
     }
 
@@ -164,13 +413,13 @@ pub unsafe extern "C" fn di_register_singleton(
 ) -> DiErrorCode {
     // Validate container
     if container.is_null() {
-        set_last_error("Container pointer is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
         return DiErrorCode::InvalidArgument;
     }
 
     // Validate type_name
     if type_name.is_null() {
-        set_last_error("Type name is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
         return DiErrorCode::InvalidArgument;
     }
 
@@ -178,14 +427,14 @@ pub unsafe extern "C" fn di_register_singleton(
     let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
         Ok(s) => s.to_string(),
         Err(_) => {
-            set_last_error("Type name is not valid UTF-8");
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
             return DiErrorCode::InvalidArgument;
         }
     };
 
     // Validate data
     if data.is_null() && data_len > 0 {
-        set_last_error("Data pointer is null but length is non-zero");
+        set_last_error(DiErrorCode::InvalidArgument, "Data pointer is null but length is non-zero");
         return DiErrorCode::InvalidArgument;
     }
 
@@ -204,18 +453,116 @@ pub unsafe extern "C" fn di_register_singleton(
     {
         let services = container.services.read().unwrap();
         if services.contains_key(&type_name_str) {
-            set_last_error(format!("Service '{}' is already registered", type_name_str));
+            set_last_error(DiErrorCode::AlreadyRegistered, format!("Service '{}' is already registered", type_name_str));
             return DiErrorCode::AlreadyRegistered;
         }
     }
 
     // Store the service data
-    let service_data: Arc<dyn Any + Send + Sync> = Arc::new(data_vec);
     container
         .services
         .write()
         .unwrap()
-        .insert(type_name_str, service_data);
+        .insert(type_name_str, Arc::new(ServiceEntry::Singleton(data_vec)));
+
+    DiErrorCode::Ok
+}
+
+/// Register a service whose bytes are produced on demand by a C callback,
+/// instead of supplied up front.
+///
+/// # Arguments
+/// - `container` - The container to register in
+/// - `type_name` - A unique string identifier for this service type (null-terminated)
+/// - `callback` - Invoked on resolve to produce the service's bytes
+/// - `free_result` - Invoked right after each `callback` call to release the
+///   buffer it wrote to `out_data`/`out_len` (not called when it reports a
+///   zero-length result)
+/// - `user_data` - Opaque pointer passed back to `callback` unchanged
+/// - `free_user_data` - Optional destructor for `user_data`, run when the
+///   registration is dropped (e.g. by `di_container_free`)
+/// - `lifetime` - Whether `callback` is re-invoked on every resolve
+///   (`Transient`) or only once (`Scoped`)
+/// - `thread_safe` - Must be non-zero: the caller's assertion that
+///   `user_data` is safe to access from whatever thread resolves this
+///   service. Registration is refused (`InvalidArgument`) otherwise.
+///
+/// # Returns
+/// Error code indicating success or failure.
+///
+/// # Safety
+/// - `container` must be a valid container pointer
+/// - `type_name` must be a valid null-terminated UTF-8 string
+/// - `user_data` must remain valid (and safe to access from any thread, per
+///   `thread_safe`) until `free_user_data` runs
+/// - `free_result` must be able to release whatever buffer `callback`
+///   allocates, on whatever thread resolves this service
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_register_factory(
+    container: *mut DiContainer,
+    type_name: *const c_char,
+    callback: DiFactoryCallback,
+    free_result: DiFreeResultFn,
+    user_data: *mut c_void,
+    free_user_data: Option<extern "C" fn(*mut c_void)>,
+    lifetime: DiFactoryLifetime,
+    thread_safe: i32,
+) -> DiErrorCode {
+    if container.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    if type_name.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees type_name is valid
+    let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => {
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
+            return DiErrorCode::InvalidArgument;
+        }
+    };
+
+    if thread_safe == 0 {
+        set_last_error(
+            DiErrorCode::InvalidArgument,
+            "di_register_factory requires thread_safe to be asserted (non-zero) - \
+             user_data may be accessed from any thread that resolves this service",
+        );
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees container is valid
+    let container = unsafe { &*container };
+
+    {
+        let services = container.services.read().unwrap();
+        if services.contains_key(&type_name_str) {
+            set_last_error(DiErrorCode::AlreadyRegistered, format!("Service '{}' is already registered", type_name_str));
+            return DiErrorCode::AlreadyRegistered;
+        }
+    }
+
+    let entry = ServiceEntry::Factory {
+        callback,
+        free_result,
+        handle: FfiCallbackHandle {
+            user_data,
+            free_user_data,
+        },
+        lifetime,
+        cached: Mutex::new(None),
+    };
+
+    container
+        .services
+        .write()
+        .unwrap()
+        .insert(type_name_str, Arc::new(entry));
 
     DiErrorCode::Ok
 }
@@ -238,7 +585,7 @@ pub unsafe extern "C" fn di_register_singleton_json(
     json_data: *const c_char,
 ) -> DiErrorCode {
     if json_data.is_null() {
-        set_last_error("JSON data is null");
+        set_last_error(DiErrorCode::InvalidArgument, "JSON data is null");
         return DiErrorCode::InvalidArgument;
     }
 
@@ -246,7 +593,7 @@ pub unsafe extern "C" fn di_register_singleton_json(
     let json_str = match unsafe { CStr::from_ptr(json_data) }.to_str() {
         Ok(s) => s,
         Err(_) => {
-            set_last_error("JSON data is not valid UTF-8");
+            set_last_error(DiErrorCode::InvalidArgument, "JSON data is not valid UTF-8");
             return DiErrorCode::InvalidArgument;
         }
     };
@@ -257,6 +604,145 @@ pub unsafe extern "C" fn di_register_singleton_json(
     unsafe { di_register_singleton(container, type_name, json_bytes.as_ptr(), json_bytes.len()) }
 }
 
+/// Register a codec under `name`, making it available to
+/// `di_register_singleton_encoded`/`di_resolve_encoded` on every container.
+///
+/// Registering a name that already exists replaces the previous codec -
+/// this is a process-global registry, not a per-container one.
+///
+/// # Arguments
+/// - `name` - The codec's name (e.g. `"msgpack"`, `"cbor"`), null-terminated
+/// - `encode` - Transforms caller-supplied data into the bytes to store
+/// - `encode_free` - Invoked right after each `encode` call to release the
+///   buffer it wrote to `out_data`/`out_len` (not called for a zero-length result)
+/// - `decode` - Transforms stored bytes into the bytes to hand back on resolve
+/// - `decode_free` - Same as `encode_free`, for `decode`'s buffer
+///
+/// # Safety
+/// - `name` must be a valid null-terminated UTF-8 string
+/// - `encode_free`/`decode_free` must be able to release whatever buffer
+///   `encode`/`decode` allocates, on whatever thread resolves a service
+///   stored under this codec
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_register_codec(
+    name: *const c_char,
+    encode: DiCodecFn,
+    encode_free: DiFreeResultFn,
+    decode: DiCodecFn,
+    decode_free: DiFreeResultFn,
+) -> DiErrorCode {
+    if name.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Codec name is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees name is valid
+    let name_str = match unsafe { CStr::from_ptr(name) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => {
+            set_last_error(DiErrorCode::InvalidArgument, "Codec name is not valid UTF-8");
+            return DiErrorCode::InvalidArgument;
+        }
+    };
+
+    codecs().write().unwrap().insert(
+        name_str,
+        Codec {
+            encode,
+            encode_free,
+            decode,
+            decode_free,
+        },
+    );
+    DiErrorCode::Ok
+}
+
+/// Register a singleton service, running `data` through `codec_name`'s
+/// `encode` transform before storing it.
+///
+/// This generalizes `di_register_singleton_json` (which is equivalent to
+/// calling this with `codec_name = "json"`) to any codec registered via
+/// `di_register_codec`.
+///
+/// # Arguments
+/// - `container` - The container to register in
+/// - `type_name` - A unique string identifier for this service type
+/// - `codec_name` - The name of a codec previously registered (or `"json"`)
+/// - `data` - Pointer to the data to encode
+/// - `data_len` - Length of `data` in bytes
+///
+/// # Returns
+/// Error code indicating success or failure.
+///
+/// # Safety
+/// - `container` must be a valid container pointer
+/// - `type_name` and `codec_name` must be valid null-terminated UTF-8 strings
+/// - `data` must point to at least `data_len` bytes
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_register_singleton_encoded(
+    container: *mut DiContainer,
+    type_name: *const c_char,
+    codec_name: *const c_char,
+    data: *const u8,
+    data_len: usize,
+) -> DiErrorCode {
+    if codec_name.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Codec name is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees codec_name is valid
+    let codec_name_str = match unsafe { CStr::from_ptr(codec_name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => {
+            set_last_error(DiErrorCode::InvalidArgument, "Codec name is not valid UTF-8");
+            return DiErrorCode::InvalidArgument;
+        }
+    };
+
+    if data.is_null() && data_len > 0 {
+        set_last_error(
+            DiErrorCode::InvalidArgument,
+            "Data pointer is null but length is non-zero",
+        );
+        return DiErrorCode::InvalidArgument;
+    }
+
+    let input: &[u8] = if data_len > 0 {
+        // SAFETY: Caller guarantees data points to data_len bytes
+        unsafe { std::slice::from_raw_parts(data, data_len) }
+    } else {
+        &[]
+    };
+
+    let registry = codecs().read().unwrap();
+    let codec = match registry.get(codec_name_str) {
+        Some(c) => c,
+        None => {
+            set_last_error(
+                DiErrorCode::InvalidArgument,
+                format!("Codec '{}' is not registered", codec_name_str),
+            );
+            return DiErrorCode::InvalidArgument;
+        }
+    };
+
+    let encoded = match invoke_codec(codec.encode, codec.encode_free, input) {
+        Ok(bytes) => bytes,
+        Err(code) => {
+            set_last_error(
+                code,
+                format!("Codec '{}' failed to encode data", codec_name_str),
+            );
+            return code;
+        }
+    };
+    drop(registry);
+
+    // SAFETY: encoded owns its bytes and is valid for encoded.len()
+    unsafe { di_register_singleton(container, type_name, encoded.as_ptr(), encoded.len()) }
+}
+
 // ============================================================================
 // Service Resolution
 // ============================================================================
@@ -281,7 +767,7 @@ pub unsafe extern "C" fn di_resolve(
 ) -> DiResult {
     // Validate container
     if container.is_null() {
-        set_last_error("Container pointer is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
         return DiResult {
             code: DiErrorCode::InvalidArgument,
             service: ptr::null_mut(),
@@ -290,7 +776,7 @@ pub unsafe extern "C" fn di_resolve(
 
     // Validate type_name
     if type_name.is_null() {
-        set_last_error("Type name is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
         return DiResult {
             code: DiErrorCode::InvalidArgument,
             service: ptr::null_mut(),
@@ -301,7 +787,7 @@ pub unsafe extern "C" fn di_resolve(
     let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
         Ok(s) => s.to_string(),
         Err(_) => {
-            set_last_error("Type name is not valid UTF-8");
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
             return DiResult {
                 code: DiErrorCode::InvalidArgument,
                 service: ptr::null_mut(),
@@ -315,27 +801,30 @@ pub unsafe extern "C" fn di_resolve(
     // Look up the service
     let services = container.services.read().unwrap();
     match services.get(&type_name_str) {
-        Some(service_arc) => {
-            // Downcast to Vec<u8>
-            if let Some(data) = service_arc.downcast_ref::<Vec<u8>>() {
+        Some(entry) => match resolve_service_bytes(entry) {
+            Ok(data) => {
                 let service = Box::new(DiService {
                     type_name: type_name_str,
-                    data: data.clone(),
+                    data,
                 });
                 DiResult {
                     code: DiErrorCode::Ok,
                     service: Box::into_raw(service),
                 }
-            } else {
-                set_last_error("Internal error: service data type mismatch");
+            }
+            Err(code) => {
+                set_last_error(code, format!(
+                    "Factory callback for '{}' failed: {:?}",
+                    type_name_str, code
+                ));
                 DiResult {
-                    code: DiErrorCode::InternalError,
+                    code,
                     service: ptr::null_mut(),
                 }
             }
-        }
+        },
         None => {
-            set_last_error(format!("Service '{}' not found", type_name_str));
+            set_last_error(DiErrorCode::NotFound, format!("Service '{}' not found", type_name_str));
             DiResult {
                 code: DiErrorCode::NotFound,
                 service: ptr::null_mut(),
@@ -366,13 +855,13 @@ pub unsafe extern "C" fn di_resolve_json(
 ) -> *mut c_char {
     // Validate container
     if container.is_null() {
-        set_last_error("Container pointer is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
         return ptr::null_mut();
     }
 
     // Validate type_name
     if type_name.is_null() {
-        set_last_error("Type name is null");
+        set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
         return ptr::null_mut();
     }
 
@@ -380,7 +869,7 @@ pub unsafe extern "C" fn di_resolve_json(
     let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
         Ok(s) => s.to_string(),
         Err(_) => {
-            set_last_error("Type name is not valid UTF-8");
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
             return ptr::null_mut();
         }
     };
@@ -391,67 +880,394 @@ pub unsafe extern "C" fn di_resolve_json(
     // Look up the service
     let services = container.services.read().unwrap();
     match services.get(&type_name_str) {
-        Some(service_arc) => {
-            // Downcast to Vec<u8>
-            if let Some(data) = service_arc.downcast_ref::<Vec<u8>>() {
-                // Convert bytes to string (assuming UTF-8 JSON)
-                match std::str::from_utf8(data) {
-                    Ok(json_str) => match CString::new(json_str) {
-                        Ok(cstr) => cstr.into_raw(),
-                        Err(_) => {
-                            set_last_error("JSON string contains null bytes");
-                            ptr::null_mut()
-                        }
-                    },
+        Some(entry) => match resolve_service_bytes(entry) {
+            Ok(data) => match std::str::from_utf8(&data) {
+                Ok(json_str) => match CString::new(json_str) {
+                    Ok(cstr) => cstr.into_raw(),
                     Err(_) => {
-                        set_last_error("Service data is not valid UTF-8");
+                        set_last_error(DiErrorCode::SerializationError, "JSON string contains null bytes");
                         ptr::null_mut()
                     }
+                },
+                Err(_) => {
+                    set_last_error(DiErrorCode::SerializationError, "Service data is not valid UTF-8");
+                    ptr::null_mut()
                 }
-            } else {
-                set_last_error("Internal error: service data type mismatch");
+            },
+            Err(code) => {
+                set_last_error(code, format!(
+                    "Factory callback for '{}' failed: {:?}",
+                    type_name_str, code
+                ));
                 ptr::null_mut()
             }
-        }
+        },
         None => {
-            set_last_error(format!("Service '{}' not found", type_name_str));
+            set_last_error(DiErrorCode::NotFound, format!("Service '{}' not found", type_name_str));
             ptr::null_mut()
         }
     }
 }
 
-/// Check if a service is registered.
+/// Resolve a service, running its stored bytes through `codec_name`'s
+/// `decode` transform before returning them.
+///
+/// This generalizes `di_resolve_json` (which is equivalent to calling this
+/// with `codec_name = "json"`, modulo the string-vs-bytes return type) to
+/// any codec registered via `di_register_codec`.
+///
+/// # Arguments
+/// - `container` - The container to resolve from
+/// - `type_name` - The service type name to resolve
+/// - `codec_name` - The name of a codec previously registered (or `"json"`)
 ///
 /// # Returns
-/// 1 if the service is registered, 0 if not, -1 on error.
+/// A `DiResult` with the decoded service data on success, or an error code
+/// on failure.
+///
+/// # Safety
+/// - `container` must be a valid container pointer
+/// - `type_name` and `codec_name` must be valid null-terminated UTF-8 strings
+/// - On success, the returned service must be freed with `di_service_free()`
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn di_contains(container: *mut DiContainer, type_name: *const c_char) -> i32 {
-    if container.is_null() || type_name.is_null() {
-        return -1;
+pub unsafe extern "C" fn di_resolve_encoded(
+    container: *mut DiContainer,
+    type_name: *const c_char,
+    codec_name: *const c_char,
+) -> DiResult {
+    if container.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
+        return DiResult {
+            code: DiErrorCode::InvalidArgument,
+            service: ptr::null_mut(),
+        };
+    }
+
+    if type_name.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
+        return DiResult {
+            code: DiErrorCode::InvalidArgument,
+            service: ptr::null_mut(),
+        };
+    }
+
+    if codec_name.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Codec name is null");
+        return DiResult {
+            code: DiErrorCode::InvalidArgument,
+            service: ptr::null_mut(),
+        };
     }
 
     // SAFETY: Caller guarantees type_name is valid
     let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => {
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
+            return DiResult {
+                code: DiErrorCode::InvalidArgument,
+                service: ptr::null_mut(),
+            };
+        }
+    };
+
+    // SAFETY: Caller guarantees codec_name is valid
+    let codec_name_str = match unsafe { CStr::from_ptr(codec_name) }.to_str() {
         Ok(s) => s,
-        Err(_) => return -1,
+        Err(_) => {
+            set_last_error(DiErrorCode::InvalidArgument, "Codec name is not valid UTF-8");
+            return DiResult {
+                code: DiErrorCode::InvalidArgument,
+                service: ptr::null_mut(),
+            };
+        }
+    };
+
+    let registry = codecs().read().unwrap();
+    let codec = match registry.get(codec_name_str) {
+        Some(c) => c,
+        None => {
+            set_last_error(
+                DiErrorCode::InvalidArgument,
+                format!("Codec '{}' is not registered", codec_name_str),
+            );
+            return DiResult {
+                code: DiErrorCode::InvalidArgument,
+                service: ptr::null_mut(),
+            };
+        }
     };
 
     // SAFETY: Caller guarantees container is valid
     let container = unsafe { &*container };
     let services = container.services.read().unwrap();
-
-    if services.contains_key(type_name_str) {
-        1
-    } else {
-        0
-    }
-}
-
-// ============================================================================
-// Service Data Access
-// ============================================================================
-
-/// Get the data pointer from a service handle.
+    match services.get(&type_name_str) {
+        Some(entry) => match resolve_service_bytes(entry) {
+            Ok(data) => match invoke_codec(codec.decode, codec.decode_free, &data) {
+                Ok(decoded) => {
+                    let service = Box::new(DiService {
+                        type_name: type_name_str,
+                        data: decoded,
+                    });
+                    DiResult {
+                        code: DiErrorCode::Ok,
+                        service: Box::into_raw(service),
+                    }
+                }
+                Err(code) => {
+                    set_last_error(
+                        code,
+                        format!(
+                            "Codec '{}' failed to decode data for '{}'",
+                            codec_name_str, type_name_str
+                        ),
+                    );
+                    DiResult {
+                        code,
+                        service: ptr::null_mut(),
+                    }
+                }
+            },
+            Err(code) => {
+                set_last_error(
+                    code,
+                    format!("Factory callback for '{}' failed: {:?}", type_name_str, code),
+                );
+                DiResult {
+                    code,
+                    service: ptr::null_mut(),
+                }
+            }
+        },
+        None => {
+            set_last_error(DiErrorCode::NotFound, format!("Service '{}' not found", type_name_str));
+            DiResult {
+                code: DiErrorCode::NotFound,
+                service: ptr::null_mut(),
+            }
+        }
+    }
+}
+
+/// Check if a service is registered.
+///
+/// # Returns
+/// 1 if the service is registered, 0 if not, -1 on error.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_contains(container: *mut DiContainer, type_name: *const c_char) -> i32 {
+    if container.is_null() || type_name.is_null() {
+        return -1;
+    }
+
+    // SAFETY: Caller guarantees type_name is valid
+    let type_name_str = match unsafe { CStr::from_ptr(type_name) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return -1,
+    };
+
+    // SAFETY: Caller guarantees container is valid
+    let container = unsafe { &*container };
+    let services = container.services.read().unwrap();
+
+    if services.contains_key(type_name_str) {
+        1
+    } else {
+        0
+    }
+}
+
+/// List every type name registered in `container`.
+///
+/// # Arguments
+/// - `container` - The container to enumerate
+/// - `out_names` - Receives a heap-allocated array of `*mut c_char`, one per
+///   registered type, null-terminated strings owned by the array
+/// - `out_count` - Receives the number of entries written to `*out_names`
+///
+/// # Returns
+/// Error code indicating success or failure. On success, `*out_names` must
+/// be freed with `di_string_array_free(*out_names, *out_count)`.
+///
+/// # Safety
+/// - `container` must be a valid container pointer
+/// - `out_names` and `out_count` must be valid, writable pointers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_list_types(
+    container: *const DiContainer,
+    out_names: *mut *mut *mut c_char,
+    out_count: *mut usize,
+) -> DiErrorCode {
+    if container.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    if out_names.is_null() || out_count.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Output pointer is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees container is valid
+    let container = unsafe { &*container };
+    let services = container.services.read().unwrap();
+
+    let mut c_strings: Vec<*mut c_char> = Vec::with_capacity(services.len());
+    for type_name in services.keys() {
+        match CString::new(type_name.as_str()) {
+            Ok(cstr) => c_strings.push(cstr.into_raw()),
+            Err(_) => {
+                // Roll back everything allocated so far rather than leak it.
+                for ptr in c_strings {
+                    drop(unsafe { CString::from_raw(ptr) });
+                }
+                set_last_error(DiErrorCode::InternalError, format!("Type name '{}' contains a null byte", type_name));
+                return DiErrorCode::InternalError;
+            }
+        }
+    }
+
+    let count = c_strings.len();
+    let boxed = c_strings.into_boxed_slice();
+    let array_ptr = Box::into_raw(boxed) as *mut *mut c_char;
+
+    // SAFETY: Caller guarantees out_names/out_count are valid, writable pointers
+    unsafe {
+        *out_names = array_ptr;
+        *out_count = count;
+    }
+
+    DiErrorCode::Ok
+}
+
+/// Free an array returned by `di_list_types`.
+///
+/// # Safety
+/// - `names` must be a pointer returned by `di_list_types`, or null
+/// - `count` must be the `out_count` value `di_list_types` wrote alongside it
+/// - After calling this function, `names` and every string it held are invalid
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_string_array_free(names: *mut *mut c_char, count: usize) {
+    if names.is_null() {
+        return;
+    }
+
+    // SAFETY: Caller guarantees this is the (ptr, len) `di_list_types` handed back.
+    let slice = unsafe { std::slice::from_raw_parts_mut(names, count) };
+    for &mut ptr in slice.iter_mut() {
+        if !ptr.is_null() {
+            // SAFETY: Each entry was allocated by CString::into_raw in `di_list_types`.
+            drop(unsafe { CString::from_raw(ptr) });
+        }
+    }
+
+    // SAFETY: `names` came from `Box::into_raw` over a boxed slice of this length.
+    drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(names, count)) });
+}
+
+/// Resolve several services in one call, acquiring the container's service
+/// map read lock only once instead of once per type.
+///
+/// Never fails the whole batch because one entry was `NotFound` (or any
+/// other per-entry error) - each `out_results[i]` carries its own
+/// `DiErrorCode` independently of the others.
+///
+/// # Arguments
+/// - `container` - The container to resolve from
+/// - `type_names` - Array of `count` null-terminated type name strings
+/// - `count` - Number of entries in `type_names` and `out_results`
+/// - `out_results` - Caller-provided array of `count` `DiResult`s to fill
+///
+/// # Returns
+/// `DiErrorCode::Ok` if every entry in `type_names` was a valid, non-null
+/// string (regardless of whether each individually resolved);
+/// `DiErrorCode::InvalidArgument` if a pointer argument is null or malformed.
+/// Check each `out_results[i].code` for the per-entry outcome.
+///
+/// # Safety
+/// - `container` must be a valid container pointer
+/// - `type_names` must point to `count` valid, null-terminated UTF-8 strings
+/// - `out_results` must point to at least `count` writable `DiResult` slots;
+///   each `.service` that comes back `Ok` must be freed with `di_service_free`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_resolve_batch(
+    container: *mut DiContainer,
+    type_names: *const *const c_char,
+    count: usize,
+    out_results: *mut DiResult,
+) -> DiErrorCode {
+    if container.is_null() {
+        set_last_error(DiErrorCode::InvalidArgument, "Container pointer is null");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    if count > 0 && (type_names.is_null() || out_results.is_null()) {
+        set_last_error(DiErrorCode::InvalidArgument, "type_names/out_results pointer is null but count is non-zero");
+        return DiErrorCode::InvalidArgument;
+    }
+
+    // SAFETY: Caller guarantees container is valid
+    let container = unsafe { &*container };
+    // SAFETY: Caller guarantees type_names points to `count` valid pointers
+    let name_ptrs = if count > 0 {
+        unsafe { std::slice::from_raw_parts(type_names, count) }
+    } else {
+        &[]
+    };
+
+    let mut parsed_names = Vec::with_capacity(count);
+    for &name_ptr in name_ptrs {
+        if name_ptr.is_null() {
+            set_last_error(DiErrorCode::InvalidArgument, "Type name is null");
+            return DiErrorCode::InvalidArgument;
+        }
+        // SAFETY: Caller guarantees each entry is a valid null-terminated string
+        match unsafe { CStr::from_ptr(name_ptr) }.to_str() {
+            Ok(s) => parsed_names.push(s),
+            Err(_) => {
+                set_last_error(DiErrorCode::InvalidArgument, "Type name is not valid UTF-8");
+                return DiErrorCode::InvalidArgument;
+            }
+        }
+    }
+
+    // Single read-lock acquisition for the whole batch.
+    let services = container.services.read().unwrap();
+    // SAFETY: Caller guarantees out_results points to at least `count` writable slots
+    let results = unsafe { std::slice::from_raw_parts_mut(out_results, count) };
+
+    for (i, type_name) in parsed_names.into_iter().enumerate() {
+        results[i] = match services.get(type_name) {
+            Some(entry) => match resolve_service_bytes(entry) {
+                Ok(data) => {
+                    let service = Box::new(DiService {
+                        type_name: type_name.to_string(),
+                        data,
+                    });
+                    DiResult {
+                        code: DiErrorCode::Ok,
+                        service: Box::into_raw(service),
+                    }
+                }
+                Err(code) => DiResult {
+                    code,
+                    service: ptr::null_mut(),
+                },
+            },
+            None => DiResult {
+                code: DiErrorCode::NotFound,
+                service: ptr::null_mut(),
+            },
+        };
+    }
+
+    DiErrorCode::Ok
+}
+
+// ============================================================================
+// Service Data Access
+// ============================================================================
+
+/// Get the data pointer from a service handle.
 ///
 /// # Returns
 /// Pointer to the service data, or NULL on error.
@@ -525,7 +1341,7 @@ pub extern "C" fn di_error_message() -> *mut c_char {
     LAST_ERROR.with(|e| {
         let error = e.borrow();
         match &*error {
-            Some(msg) => match CString::new(msg.as_str()) {
+            Some((_, msg)) => match CString::new(msg.as_str()) {
                 Ok(cstr) => cstr.into_raw(),
                 Err(_) => ptr::null_mut(),
             },
@@ -534,6 +1350,69 @@ pub extern "C" fn di_error_message() -> *mut c_char {
     })
 }
 
+/// Structured information about the last error on this thread.
+///
+/// `class` is a stable, statically-allocated slug (e.g. `"NotFound"`) that
+/// must NOT be freed. `message` is a heap-allocated, human-readable string
+/// that must be freed with `di_string_free()`, or NULL if there was no error.
+#[repr(C)]
+pub struct DiErrorInfo {
+    pub code: DiErrorCode,
+    pub class: *const c_char,
+    pub message: *mut c_char,
+}
+
+/// Get structured information about the last error on this thread.
+///
+/// Unlike `di_error_message()`, this exposes a stable `class` slug that
+/// callers can branch on (or map to a language-specific exception type)
+/// without string-matching the human-readable message.
+///
+/// # Returns
+/// `1` if an error was present and `*out` was filled in, `0` if there was
+/// no error (in which case `*out` is zeroed and `message`/`class` are NULL).
+///
+/// # Safety
+/// `out` must be a valid, writable pointer to a `DiErrorInfo`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn di_last_error(out: *mut DiErrorInfo) -> i32 {
+    if out.is_null() {
+        return 0;
+    }
+
+    LAST_ERROR.with(|e| {
+        let error = e.borrow();
+        match &*error {
+            Some((code, msg)) => {
+                let message = match CString::new(msg.as_str()) {
+                    Ok(cstr) => cstr.into_raw(),
+                    Err(_) => ptr::null_mut(),
+                };
+                // SAFETY: Caller guarantees out is a valid, writable pointer
+                unsafe {
+                    *out = DiErrorInfo {
+                        code: *code,
+                        class: error_class(*code).as_ptr(),
+                        message,
+                    };
+                }
+                1
+            }
+            None => {
+                // SAFETY: Caller guarantees out is a valid, writable pointer
+                unsafe {
+                    *out = DiErrorInfo {
+                        code: DiErrorCode::Ok,
+                        class: ptr::null(),
+                        message: ptr::null_mut(),
+                    };
+                }
+                0
+            }
+        }
+    })
+}
+
 /// Clear the last error message.
 #[unsafe(no_mangle)]
 pub extern "C" fn di_error_clear() {
@@ -675,4 +1554,456 @@ mod tests {
             di_container_free(parent);
         }
     }
+
+    extern "C" fn counting_factory(
+        user_data: *mut c_void,
+        out_data: *mut *mut u8,
+        out_len: *mut usize,
+    ) -> DiErrorCode {
+        // SAFETY: tests pass an `AtomicU32` pointer as `user_data`.
+        let counter = unsafe { &*(user_data as *const std::sync::atomic::AtomicU32) };
+        let n = counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+
+        let bytes = Box::new([n as u8]);
+        let ptr = Box::into_raw(bytes) as *mut u8;
+        unsafe {
+            *out_data = ptr;
+            *out_len = 1;
+        }
+        DiErrorCode::Ok
+    }
+
+    extern "C" fn counting_factory_free(data: *mut u8, len: usize) {
+        if data.is_null() {
+            return;
+        }
+        // SAFETY: `counting_factory` always allocates via `Box::new`/
+        // `Box::into_raw` with this exact `len`.
+        drop(unsafe { Box::from_raw(std::slice::from_raw_parts_mut(data, len) as *mut [u8]) });
+    }
+
+    #[test]
+    fn test_register_factory_transient_invokes_every_resolve() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Counter").unwrap();
+            let counter = std::sync::atomic::AtomicU32::new(0);
+
+            let result = di_register_factory(
+                container,
+                type_name.as_ptr(),
+                counting_factory,
+                counting_factory_free,
+                &counter as *const _ as *mut c_void,
+                None,
+                DiFactoryLifetime::Transient,
+                1,
+            );
+            assert_eq!(result, DiErrorCode::Ok);
+
+            let first = di_resolve(container, type_name.as_ptr());
+            assert_eq!(first.code, DiErrorCode::Ok);
+            let first_value = *di_service_data(first.service);
+            di_service_free(first.service);
+
+            let second = di_resolve(container, type_name.as_ptr());
+            let second_value = *di_service_data(second.service);
+            di_service_free(second.service);
+
+            assert_eq!(first_value, 1);
+            assert_eq!(second_value, 2);
+
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_register_factory_scoped_caches_first_result() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Counter").unwrap();
+            let counter = std::sync::atomic::AtomicU32::new(0);
+
+            di_register_factory(
+                container,
+                type_name.as_ptr(),
+                counting_factory,
+                counting_factory_free,
+                &counter as *const _ as *mut c_void,
+                None,
+                DiFactoryLifetime::Scoped,
+                1,
+            );
+
+            let first = di_resolve(container, type_name.as_ptr());
+            let first_value = *di_service_data(first.service);
+            di_service_free(first.service);
+
+            let second = di_resolve(container, type_name.as_ptr());
+            let second_value = *di_service_data(second.service);
+            di_service_free(second.service);
+
+            assert_eq!(first_value, 1);
+            assert_eq!(second_value, 1);
+
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_register_factory_requires_thread_safe_flag() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Counter").unwrap();
+            let counter = std::sync::atomic::AtomicU32::new(0);
+
+            let result = di_register_factory(
+                container,
+                type_name.as_ptr(),
+                counting_factory,
+                counting_factory_free,
+                &counter as *const _ as *mut c_void,
+                None,
+                DiFactoryLifetime::Transient,
+                0,
+            );
+            assert_eq!(result, DiErrorCode::InvalidArgument);
+            assert_eq!(di_contains(container, type_name.as_ptr()), 0);
+
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_register_factory_runs_destructor_on_container_free() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static FREED: AtomicBool = AtomicBool::new(false);
+
+        extern "C" fn ignore_factory(
+            _user_data: *mut c_void,
+            out_data: *mut *mut u8,
+            out_len: *mut usize,
+        ) -> DiErrorCode {
+            // SAFETY: caller (`di_resolve`) provides valid out params.
+            unsafe {
+                *out_data = ptr::null_mut();
+                *out_len = 0;
+            }
+            DiErrorCode::Ok
+        }
+
+        extern "C" fn ignore_factory_free(_data: *mut u8, _len: usize) {
+            // `ignore_factory` always reports a zero-length result, so this
+            // is never actually invoked - it only needs to satisfy the
+            // `DiFreeResultFn` signature.
+        }
+
+        extern "C" fn free_flag(user_data: *mut c_void) {
+            // SAFETY: `user_data` is `&FREED` for this test.
+            let flag = unsafe { &*(user_data as *const AtomicBool) };
+            flag.store(true, Ordering::SeqCst);
+        }
+
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Counter").unwrap();
+
+            di_register_factory(
+                container,
+                type_name.as_ptr(),
+                ignore_factory,
+                ignore_factory_free,
+                &FREED as *const _ as *mut c_void,
+                Some(free_flag),
+                DiFactoryLifetime::Transient,
+                1,
+            );
+
+            di_container_free(container);
+            assert!(FREED.load(Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn test_list_types_returns_all_registered_names() {
+        unsafe {
+            let container = di_container_new();
+            let a = CString::new("Alpha").unwrap();
+            let b = CString::new("Beta").unwrap();
+            let data = b"x";
+
+            di_register_singleton(container, a.as_ptr(), data.as_ptr(), data.len());
+            di_register_singleton(container, b.as_ptr(), data.as_ptr(), data.len());
+
+            let mut names: *mut *mut c_char = ptr::null_mut();
+            let mut count: usize = 0;
+            let code = di_list_types(container, &mut names, &mut count);
+            assert_eq!(code, DiErrorCode::Ok);
+            assert_eq!(count, 2);
+
+            let slice = std::slice::from_raw_parts(names, count);
+            let mut found: Vec<String> = slice
+                .iter()
+                .map(|&p| CStr::from_ptr(p).to_str().unwrap().to_string())
+                .collect();
+            found.sort();
+            assert_eq!(found, vec!["Alpha".to_string(), "Beta".to_string()]);
+
+            di_string_array_free(names, count);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_list_types_on_empty_container_returns_zero_count() {
+        unsafe {
+            let container = di_container_new();
+
+            let mut names: *mut *mut c_char = ptr::null_mut();
+            let mut count: usize = 0;
+            let code = di_list_types(container, &mut names, &mut count);
+            assert_eq!(code, DiErrorCode::Ok);
+            assert_eq!(count, 0);
+
+            di_string_array_free(names, count);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_resolve_batch_mixes_found_and_not_found_independently() {
+        unsafe {
+            let container = di_container_new();
+            let a = CString::new("Alpha").unwrap();
+            let missing = CString::new("Missing").unwrap();
+            let data = b"hello";
+
+            di_register_singleton(container, a.as_ptr(), data.as_ptr(), data.len());
+
+            let type_names = [a.as_ptr(), missing.as_ptr()];
+            let mut results: [DiResult; 2] = [
+                DiResult {
+                    code: DiErrorCode::Ok,
+                    service: ptr::null_mut(),
+                },
+                DiResult {
+                    code: DiErrorCode::Ok,
+                    service: ptr::null_mut(),
+                },
+            ];
+
+            let code = di_resolve_batch(container, type_names.as_ptr(), 2, results.as_mut_ptr());
+            assert_eq!(code, DiErrorCode::Ok);
+
+            assert_eq!(results[0].code, DiErrorCode::Ok);
+            assert!(!results[0].service.is_null());
+            assert_eq!(di_service_data_len(results[0].service), 5);
+            di_service_free(results[0].service);
+
+            assert_eq!(results[1].code, DiErrorCode::NotFound);
+            assert!(results[1].service.is_null());
+
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_resolve_batch_with_zero_count_is_a_no_op() {
+        unsafe {
+            let container = di_container_new();
+            let code = di_resolve_batch(container, ptr::null(), 0, ptr::null_mut());
+            assert_eq!(code, DiErrorCode::Ok);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_last_error_reports_code_and_class() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("NonExistent").unwrap();
+
+            let resolve_result = di_resolve(container, type_name.as_ptr());
+            assert_eq!(resolve_result.code, DiErrorCode::NotFound);
+
+            let mut info = DiErrorInfo {
+                code: DiErrorCode::Ok,
+                class: ptr::null(),
+                message: ptr::null_mut(),
+            };
+            let had_error = di_last_error(&mut info);
+            assert_eq!(had_error, 1);
+            assert_eq!(info.code, DiErrorCode::NotFound);
+            assert_eq!(CStr::from_ptr(info.class).to_str().unwrap(), "NotFound");
+            assert!(!info.message.is_null());
+            assert_eq!(
+                CStr::from_ptr(info.message).to_str().unwrap(),
+                "Service 'NonExistent' not found"
+            );
+
+            di_string_free(info.message);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_last_error_reports_no_error_after_clear() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("NonExistent").unwrap();
+            di_resolve(container, type_name.as_ptr());
+            di_error_clear();
+
+            let mut info = DiErrorInfo {
+                code: DiErrorCode::NotFound,
+                class: ptr::null(),
+                message: ptr::null_mut(),
+            };
+            let had_error = di_last_error(&mut info);
+            assert_eq!(had_error, 0);
+            assert_eq!(info.code, DiErrorCode::Ok);
+            assert!(info.class.is_null());
+            assert!(info.message.is_null());
+
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_error_class_slugs_are_stable() {
+        assert_eq!(
+            error_class(DiErrorCode::NotFound).to_str().unwrap(),
+            "NotFound"
+        );
+        assert_eq!(
+            error_class(DiErrorCode::AlreadyRegistered).to_str().unwrap(),
+            "AlreadyRegistered"
+        );
+        assert_eq!(
+            error_class(DiErrorCode::SerializationError).to_str().unwrap(),
+            "SerializationError"
+        );
+    }
+
+    extern "C" fn reverse_codec(
+        data: *const u8,
+        len: usize,
+        out_data: *mut *mut u8,
+        out_len: *mut usize,
+    ) -> DiErrorCode {
+        // SAFETY: tests pass a valid (data, len) pair.
+        let mut bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        bytes.reverse();
+        let boxed = bytes.into_boxed_slice();
+        let out_bytes_len = boxed.len();
+        let ptr = Box::into_raw(boxed) as *mut u8;
+        unsafe {
+            *out_data = ptr;
+            *out_len = out_bytes_len;
+        }
+        DiErrorCode::Ok
+    }
+
+    extern "C" fn reverse_codec_free(data: *mut u8, len: usize) {
+        if data.is_null() {
+            return;
+        }
+        // SAFETY: `reverse_codec` always allocates via `Vec::into_boxed_slice`/
+        // `Box::into_raw` with this exact `len`.
+        drop(unsafe { Box::from_raw(std::slice::from_raw_parts_mut(data, len) as *mut [u8]) });
+    }
+
+    #[test]
+    fn test_register_singleton_encoded_with_default_json_codec_roundtrips() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Config").unwrap();
+            let codec_name = CString::new("json").unwrap();
+            let data = br#"{"debug":true}"#;
+
+            let code = di_register_singleton_encoded(
+                container,
+                type_name.as_ptr(),
+                codec_name.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+            );
+            assert_eq!(code, DiErrorCode::Ok);
+
+            let result = di_resolve_encoded(container, type_name.as_ptr(), codec_name.as_ptr());
+            assert_eq!(result.code, DiErrorCode::Ok);
+            let data_ptr = di_service_data(result.service);
+            let resolved = std::slice::from_raw_parts(data_ptr, di_service_data_len(result.service));
+            assert_eq!(resolved, data);
+
+            di_service_free(result.service);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_register_codec_is_used_by_encoded_register_and_resolve() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Reversed").unwrap();
+            let codec_name = CString::new("reverse").unwrap();
+
+            let reg_code = di_register_codec(
+                codec_name.as_ptr(),
+                reverse_codec,
+                reverse_codec_free,
+                reverse_codec,
+                reverse_codec_free,
+            );
+            assert_eq!(reg_code, DiErrorCode::Ok);
+
+            let data = b"hello";
+            let code = di_register_singleton_encoded(
+                container,
+                type_name.as_ptr(),
+                codec_name.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+            );
+            assert_eq!(code, DiErrorCode::Ok);
+
+            // Stored bytes are reversed once by encode.
+            let raw = di_resolve(container, type_name.as_ptr());
+            assert_eq!(raw.code, DiErrorCode::Ok);
+            let raw_data = std::slice::from_raw_parts(
+                di_service_data(raw.service),
+                di_service_data_len(raw.service),
+            );
+            assert_eq!(raw_data, b"olleh");
+            di_service_free(raw.service);
+
+            // Decoding reverses it back to the original.
+            let result = di_resolve_encoded(container, type_name.as_ptr(), codec_name.as_ptr());
+            assert_eq!(result.code, DiErrorCode::Ok);
+            let decoded = std::slice::from_raw_parts(
+                di_service_data(result.service),
+                di_service_data_len(result.service),
+            );
+            assert_eq!(decoded, data);
+
+            di_service_free(result.service);
+            di_container_free(container);
+        }
+    }
+
+    #[test]
+    fn test_resolve_encoded_with_unregistered_codec_returns_invalid_argument() {
+        unsafe {
+            let container = di_container_new();
+            let type_name = CString::new("Anything").unwrap();
+            let codec_name = CString::new("does-not-exist").unwrap();
+
+            let result = di_resolve_encoded(container, type_name.as_ptr(), codec_name.as_ptr());
+            assert_eq!(result.code, DiErrorCode::InvalidArgument);
+            assert!(result.service.is_null());
+
+            di_container_free(container);
+        }
+    }
 }
@@ -103,20 +103,39 @@
 //! - **Thread-local cache**: Avoid map lookups for hot services
 //! - **Zero allocation resolve**: Returns `Arc<T>` directly, no cloning
 
+mod builder;
 mod container;
 mod error;
 mod factory;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 #[cfg(feature = "logging")]
 pub mod logging;
+pub mod metrics;
+mod object_pool;
 mod provider;
+mod pool;
+pub mod lifecycle;
+#[cfg(feature = "config")]
+pub mod registry;
 mod scope;
 mod storage;
+pub mod sync_mode;
+pub mod typed;
+pub mod verified;
 
+pub use builder::*;
 pub use container::*;
 pub use error::*;
 pub use factory::*;
+pub use object_pool::{ObjectPool, Pooled, Reset};
+pub use pool::PoolGuard;
 pub use provider::*;
 pub use scope::*;
+pub use storage::{
+    CacheFactory, CacheStorage, HashMapCache, HashMapCacheFactory, LruCache, LruCacheFactory,
+    ServiceHandle, ServiceKey,
+};
 
 // Re-export tracing macros for convenience when logging feature is enabled
 #[cfg(feature = "logging")]
@@ -128,8 +147,8 @@ pub use std::sync::Arc;
 /// Prelude for convenient imports
 pub mod prelude {
     pub use crate::{
-        BatchRegistrar, Container, DiError, Factory, Injectable, Lifetime, Provider, Result, Scope,
-        ScopedContainer,
+        BatchRegistrar, Container, ContainerBuilder, DiError, Disposable, Factory, Injectable,
+        Lifetime, PoolGuard, Provider, Result, Scope, ScopedContainer,
     };
     pub use std::sync::Arc;
 }
@@ -0,0 +1,118 @@
+//! Startup and readiness hooks for long-lived services.
+//!
+//! `Container::singleton`/`lazy`/`verified::ServiceProvider::provide*` all
+//! construct a service the first time something resolves it. That's fine for
+//! most services, but a DB pool that needs to open its connections, a
+//! migrator that needs to run migrations, or a cache that needs to warm
+//! itself usually wants that work done up front, in dependency order, before
+//! the process starts serving traffic - not implicitly, on whichever request
+//! happens to resolve it first.
+//!
+//! [`Lifecycle`] declares that startup behavior; register it with
+//! [`Container::register_lifecycle`](crate::Container::register_lifecycle)
+//! and run it with
+//! [`Container::initialize_eager`](crate::Container::initialize_eager).
+//! [`Container::health_check`](crate::Container::health_check) reports the
+//! runtime result of each registered service's [`Lifecycle::check`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use dependency_injector::{Container, ResolutionError};
+//! use dependency_injector::lifecycle::Lifecycle;
+//! use dependency_injector::verified::{Service, ServiceProvider};
+//! use std::sync::atomic::{AtomicBool, Ordering};
+//!
+//! struct DbPool {
+//!     connected: AtomicBool,
+//! }
+//!
+//! impl Service for DbPool {
+//!     type Dependencies = ();
+//!     fn create(_: ()) -> Self {
+//!         DbPool { connected: AtomicBool::new(false) }
+//!     }
+//! }
+//!
+//! impl Lifecycle for DbPool {
+//!     fn on_init(&self, _container: &Container) -> Result<(), ResolutionError> {
+//!         self.connected.store(true, Ordering::SeqCst);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let container = Container::new();
+//! container.provide_singleton::<DbPool>();
+//! container.register_lifecycle::<DbPool>();
+//!
+//! container.initialize_eager().unwrap();
+//! assert!(container.get::<DbPool>().unwrap().connected.load(Ordering::SeqCst));
+//! assert!(container.health_check().is_healthy());
+//! ```
+
+use crate::{Container, ResolutionError};
+
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
+/// A service with startup/readiness behavior beyond plain construction.
+///
+/// Implement this alongside `verified::Service` (or any other registration)
+/// and call [`Container::register_lifecycle`](crate::Container::register_lifecycle)
+/// to opt the type into [`Container::initialize_eager`](crate::Container::initialize_eager)
+/// and [`Container::health_check`](crate::Container::health_check).
+pub trait Lifecycle: Send + Sync + 'static {
+    /// Run once this service (and every dependency ahead of it in the
+    /// declared graph) has already been constructed - see
+    /// `Container::initialize_eager`. Defaults to doing nothing.
+    #[allow(unused_variables)]
+    fn on_init(&self, container: &Container) -> std::result::Result<(), ResolutionError> {
+        Ok(())
+    }
+
+    /// Async counterpart to `on_init`, for startup work that needs to
+    /// `.await` (opening a DB pool, running a migration over the network).
+    /// Defaults to running the synchronous `on_init`.
+    #[cfg(feature = "async")]
+    fn on_init_async<'a>(
+        &'a self,
+        container: &'a Container,
+    ) -> Pin<Box<dyn Future<Output = std::result::Result<(), ResolutionError>> + Send + 'a>> {
+        Box::pin(async move { self.on_init(container) })
+    }
+
+    /// Report this service's current health, for `Container::health_check()`.
+    /// Defaults to always healthy.
+    fn check(&self) -> HealthStatus {
+        HealthStatus::Healthy
+    }
+}
+
+/// Result of a single [`Lifecycle::check`] call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// The service is ready to serve traffic.
+    Healthy,
+    /// The service isn't ready; the string is a human-readable reason.
+    Unhealthy(String),
+}
+
+/// Readiness report returned by
+/// [`Container::health_check`](crate::Container::health_check) - one entry
+/// per service registered via
+/// [`Container::register_lifecycle`](crate::Container::register_lifecycle),
+/// sorted by type name for deterministic output.
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    /// `(type_name, status)` for each registered service.
+    pub services: Vec<(&'static str, HealthStatus)>,
+}
+
+impl HealthReport {
+    /// `true` if every registered service reported `HealthStatus::Healthy`.
+    pub fn is_healthy(&self) -> bool {
+        self.services.iter().all(|(_, status)| *status == HealthStatus::Healthy)
+    }
+}
@@ -22,11 +22,22 @@
 //! logging::init_pretty();
 //!
 //! // Or use builder for custom configuration
-//! logging::builder()
+//! let handle = logging::builder()
 //!     .with_level(tracing::Level::DEBUG)
 //!     .with_target("dependency_injector")
 //!     .json()
 //!     .init();
+//!
+//! // The returned handle lets you bump verbosity later, e.g. from an
+//! // admin endpoint, without restarting the process
+//! handle.set_level(tracing::Level::TRACE).unwrap();
+//!
+//! // Or install a ResolutionProfiler to see where resolve time goes
+//! let profiler = std::sync::Arc::new(logging::ResolutionProfiler::new());
+//! tracing_subscriber::registry().with(profiler.clone()).init();
+//! for entry in profiler.report() {
+//!     println!("{}: {} resolves, {:?} total", entry.type_name, entry.count, entry.total);
+//! }
 //! ```
 
 #[cfg(feature = "logging")]
@@ -51,12 +62,62 @@ pub struct LoggingBuilder {
     level: Level,
     format: LogFormat,
     target: Option<&'static str>,
+    use_env: bool,
+    directives: Vec<String>,
+    capture_log: bool,
     with_file: bool,
     with_line_number: bool,
     with_thread_ids: bool,
     with_thread_names: bool,
 }
 
+/// Handle returned by [`LoggingBuilder::init`]/[`LoggingBuilder::try_init`]
+/// for adjusting the active filter at runtime, without restarting the
+/// process (e.g. from an admin endpoint that temporarily bumps the level).
+///
+/// Wraps a [`tracing_subscriber::reload::Handle`] around the `EnvFilter`
+/// installed at `init` time.
+#[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+#[derive(Clone)]
+pub struct LoggingHandle {
+    filter: tracing_subscriber::reload::Handle<tracing_subscriber::EnvFilter, tracing_subscriber::Registry>,
+}
+
+#[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+impl LoggingHandle {
+    /// Replace the active filter with a single minimum level, across all targets.
+    pub fn set_level(&self, level: Level) -> std::result::Result<(), tracing_subscriber::reload::Error> {
+        self.filter
+            .modify(|filter| *filter = tracing_subscriber::EnvFilter::new(level.to_string()))
+    }
+
+    /// Replace the active filter with an arbitrary `EnvFilter` directive
+    /// string, e.g. `"dependency_injector=trace"`.
+    pub fn set_target_filter(&self, directive: &str) -> std::result::Result<(), tracing_subscriber::reload::Error> {
+        self.filter
+            .modify(|filter| *filter = tracing_subscriber::EnvFilter::new(directive))
+    }
+}
+
+/// No-op handle returned when neither `logging-json` nor `logging-pretty` is
+/// enabled - there's no live subscriber to reload.
+#[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingHandle;
+
+#[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
+impl LoggingHandle {
+    /// No-op: requires logging-json or logging-pretty feature
+    pub fn set_level(&self, _level: Level) -> std::result::Result<(), std::convert::Infallible> {
+        Ok(())
+    }
+
+    /// No-op: requires logging-json or logging-pretty feature
+    pub fn set_target_filter(&self, _directive: &str) -> std::result::Result<(), std::convert::Infallible> {
+        Ok(())
+    }
+}
+
 #[cfg(feature = "logging")]
 impl Default for LoggingBuilder {
     fn default() -> Self {
@@ -64,6 +125,9 @@ impl Default for LoggingBuilder {
             level: Level::DEBUG,
             format: LogFormat::Json,
             target: None,
+            use_env: false,
+            directives: Vec::new(),
+            capture_log: false,
             with_file: false,
             with_line_number: false,
             with_thread_ids: false,
@@ -126,6 +190,38 @@ impl LoggingBuilder {
         self.with_target_filter("dependency_injector")
     }
 
+    /// Seed the filter from the `RUST_LOG` environment variable, falling
+    /// back to the configured level (via `with_level`/`trace`/`debug`/...)
+    /// when it's unset or fails to parse.
+    ///
+    /// Overrides [`LoggingBuilder::with_target_filter`] as the filter's base
+    /// directive - combine with [`LoggingBuilder::with_directive`] to append
+    /// per-module overrides regardless of where the base comes from.
+    pub fn with_env(mut self) -> Self {
+        self.use_env = true;
+        self
+    }
+
+    /// Append an arbitrary `EnvFilter` directive string, e.g.
+    /// `"dependency_injector::scope=trace,dependency_injector::factory=debug"`.
+    ///
+    /// May be called more than once; directives accumulate and are merged
+    /// with the base filter (from [`LoggingBuilder::with_target_filter`] or
+    /// [`LoggingBuilder::with_env`]) when the subscriber is installed.
+    pub fn with_directive(mut self, directive: &str) -> Self {
+        self.directives.push(directive.to_string());
+        self
+    }
+
+    /// Bridge `log`-crate records (e.g. from a dependency that doesn't use
+    /// `tracing`) into this subscriber on `init`/`try_init`, so they appear
+    /// alongside the container's own spans instead of vanishing. See
+    /// [`capture_log`] for the standalone version of this setup.
+    pub fn capture_log(mut self) -> Self {
+        self.capture_log = true;
+        self
+    }
+
     /// Include file names in log output
     pub fn with_file(mut self) -> Self {
         self.with_file = true;
@@ -168,18 +264,51 @@ impl LoggingBuilder {
         self
     }
 
-    /// Initialize the logging subscriber with the configured settings
+    /// Initialize the logging subscriber with the configured settings,
+    /// returning a [`LoggingHandle`] for adjusting the filter afterwards.
     ///
     /// Requires either `logging-json` or `logging-pretty` feature to be enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a global subscriber has already been set (`init` can only
+    /// succeed once per process). Use [`LoggingBuilder::try_init`] if that
+    /// might happen, e.g. in tests.
     #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
-    pub fn init(self) {
-        use tracing_subscriber::{EnvFilter, fmt, prelude::*};
+    pub fn init(self) -> LoggingHandle {
+        self.try_init()
+            .expect("global tracing subscriber already set")
+    }
 
-        let filter = if let Some(target) = self.target {
-            EnvFilter::new(format!("{}={}", target, self.level))
+    /// Like [`LoggingBuilder::init`], but returns a
+    /// [`tracing_subscriber::util::TryInitError`] instead of panicking if a
+    /// global subscriber is already installed.
+    #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+    pub fn try_init(self) -> Result<LoggingHandle, tracing_subscriber::util::TryInitError> {
+        use tracing_subscriber::{EnvFilter, fmt, prelude::*, reload};
+
+        if self.capture_log {
+            capture_log_at(self.level);
+        }
+
+        let mut directive_str = if self.use_env {
+            std::env::var("RUST_LOG").unwrap_or_default()
+        } else if let Some(target) = self.target {
+            format!("{}={}", target, self.level)
         } else {
-            EnvFilter::new(self.level.to_string())
+            String::new()
         };
+        for directive in &self.directives {
+            if !directive_str.is_empty() {
+                directive_str.push(',');
+            }
+            directive_str.push_str(directive);
+        }
+
+        let filter = EnvFilter::builder()
+            .with_default_directive(tracing_subscriber::filter::LevelFilter::from(self.level).into())
+            .parse_lossy(directive_str);
+        let (filter, filter_handle) = reload::Layer::new(filter);
 
         match self.format {
             LogFormat::Json => {
@@ -196,7 +325,7 @@ impl LoggingBuilder {
                     tracing_subscriber::registry()
                         .with(filter)
                         .with(subscriber)
-                        .init();
+                        .try_init()?;
                 }
                 #[cfg(not(feature = "logging-json"))]
                 {
@@ -211,7 +340,7 @@ impl LoggingBuilder {
                     tracing_subscriber::registry()
                         .with(filter)
                         .with(subscriber)
-                        .init();
+                        .try_init()?;
                 }
             }
             LogFormat::Pretty => {
@@ -226,7 +355,7 @@ impl LoggingBuilder {
                 tracing_subscriber::registry()
                     .with(filter)
                     .with(subscriber)
-                    .init();
+                    .try_init()?;
             }
             LogFormat::Compact => {
                 let subscriber = fmt::layer()
@@ -240,16 +369,27 @@ impl LoggingBuilder {
                 tracing_subscriber::registry()
                     .with(filter)
                     .with(subscriber)
-                    .init();
+                    .try_init()?;
             }
         }
+
+        Ok(LoggingHandle {
+            filter: filter_handle,
+        })
     }
 
     /// Initialize (no-op when subscriber features not available)
     #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
-    pub fn init(self) {
+    pub fn init(self) -> LoggingHandle {
         // No-op: tracing-subscriber not enabled
         // Users should use logging-json or logging-pretty features
+        LoggingHandle
+    }
+
+    /// Initialize (no-op when subscriber features not available)
+    #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
+    pub fn try_init(self) -> Result<LoggingHandle, std::convert::Infallible> {
+        Ok(LoggingHandle)
     }
 }
 
@@ -264,21 +404,22 @@ pub fn builder() -> LoggingBuilder {
 /// Uses JSON format if `logging-json` feature is enabled,
 /// otherwise uses pretty format if `logging-pretty` is enabled.
 #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
-pub fn init() {
+pub fn init() -> LoggingHandle {
     #[cfg(feature = "logging-json")]
     {
-        init_json();
+        init_json()
     }
     #[cfg(all(feature = "logging-pretty", not(feature = "logging-json")))]
     {
-        init_pretty();
+        init_pretty()
     }
 }
 
 /// Initialize logging (no-op when subscriber features not available)
 #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
-pub fn init() {
+pub fn init() -> LoggingHandle {
     // No-op: requires logging-json or logging-pretty feature
+    LoggingHandle
 }
 
 /// Initialize JSON structured logging
@@ -291,14 +432,15 @@ pub fn init() {
 /// {"timestamp":"2024-01-01T00:00:00.000Z","level":"DEBUG","target":"dependency_injector","message":"Creating new DI container"}
 /// ```
 #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
-pub fn init_json() {
-    builder().json().debug().init();
+pub fn init_json() -> LoggingHandle {
+    builder().json().debug().init()
 }
 
 /// Initialize JSON logging (no-op when not available)
 #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
-pub fn init_json() {
+pub fn init_json() -> LoggingHandle {
     // No-op: requires logging-json or logging-pretty feature
+    LoggingHandle
 }
 
 /// Initialize pretty colorful logging
@@ -311,28 +453,264 @@ pub fn init_json() {
 ///   2024-01-01T00:00:00.000Z DEBUG dependency_injector: Creating new DI container
 /// ```
 #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
-pub fn init_pretty() {
-    builder().pretty().debug().init();
+pub fn init_pretty() -> LoggingHandle {
+    builder().pretty().debug().init()
 }
 
 /// Initialize pretty logging (no-op when not available)
 #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
-pub fn init_pretty() {
+pub fn init_pretty() -> LoggingHandle {
     // No-op: requires logging-json or logging-pretty feature
+    LoggingHandle
 }
 
 /// Initialize logging for dependency-injector only (filters other crates)
 #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
-pub fn init_di_only() {
-    builder().di_only().debug().init();
+pub fn init_di_only() -> LoggingHandle {
+    builder().di_only().debug().init()
 }
 
 /// Initialize DI-only logging (no-op when not available)
 #[cfg(not(any(feature = "logging-json", feature = "logging-pretty")))]
-pub fn init_di_only() {
+pub fn init_di_only() -> LoggingHandle {
     // No-op: requires logging-json or logging-pretty feature
+    LoggingHandle
+}
+
+/// Bridge `log`-crate records into the tracing pipeline.
+///
+/// Many dependencies a container manages (database drivers, HTTP clients,
+/// etc.) emit records through the `log` crate facade rather than `tracing`,
+/// so without this they never reach `logging`'s JSON/pretty/compact output.
+/// This installs [`tracing_log::LogTracer`] so those records are re-emitted
+/// as tracing events and picked up by whatever subscriber is active.
+///
+/// Idempotent: safe to call more than once (e.g. once from here and once
+/// from [`LoggingBuilder::capture_log`]) - a second `LogTracer::init()` call
+/// always fails with "a global logger was already set", which is ignored.
+/// Sets the `log` facade's max level to `TRACE` so all the filtering
+/// happens in the `tracing` layer instead.
+#[cfg(feature = "logging")]
+pub fn capture_log() {
+    capture_log_at(Level::TRACE);
+}
+
+/// Like [`capture_log`], but sets the `log` facade's max level from `level`
+/// instead of always allowing everything through. Used by
+/// [`LoggingBuilder::capture_log`] to line the `log` bridge up with the
+/// builder's configured level.
+#[cfg(feature = "logging")]
+fn capture_log_at(level: Level) {
+    let _ = tracing_log::LogTracer::init();
+    log::set_max_level(match level {
+        Level::ERROR => log::LevelFilter::Error,
+        Level::WARN => log::LevelFilter::Warn,
+        Level::INFO => log::LevelFilter::Info,
+        Level::DEBUG => log::LevelFilter::Debug,
+        Level::TRACE => log::LevelFilter::Trace,
+    });
+}
+
+// =============================================================================
+// Resolution Profiler
+// =============================================================================
+
+#[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+mod profiler {
+    use ahash::RandomState;
+    use dashmap::DashMap;
+    use once_cell::sync::OnceCell;
+    use std::fmt::Debug;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::time::{Duration, Instant};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing_subscriber::layer::{Context, Layer};
+    use tracing_subscriber::registry::LookupSpan;
+
+    /// Per-type-name resolution stats accumulated by [`ResolutionProfiler`].
+    #[derive(Debug, Default)]
+    struct RawStats {
+        count: AtomicU64,
+        total_nanos: AtomicU64,
+        max_nanos: AtomicU64,
+    }
+
+    impl RawStats {
+        fn record(&self, elapsed: Duration) {
+            let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+            self.count.fetch_add(1, Ordering::Relaxed);
+            self.total_nanos.fetch_add(nanos, Ordering::Relaxed);
+            self.max_nanos.fetch_max(nanos, Ordering::Relaxed);
+        }
+    }
+
+    /// One row of [`ResolutionProfiler::report`] - aggregate timing for every
+    /// resolve of a given type.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ProfileEntry {
+        /// The resolved type's name, as returned by `std::any::type_name`.
+        pub type_name: &'static str,
+        /// Number of times this type was resolved (each transient call counts
+        /// separately, even though every call produces a fresh instance).
+        pub count: u64,
+        /// Sum of time spent inside `Container::get`/`try_get` for this type.
+        pub total: Duration,
+        /// The single slowest resolve observed for this type.
+        pub max: Duration,
+    }
+
+    /// Interns a type name as a `&'static str` so it can be used as a `DashMap`
+    /// key without re-allocating on every resolve. The set of distinct type
+    /// names resolved by a program is small and fixed at compile time, so the
+    /// one-time leak per unique name is bounded.
+    fn intern(name: &str) -> &'static str {
+        static INTERNED: OnceCell<DashMap<String, &'static str, RandomState>> = OnceCell::new();
+        let table = INTERNED.get_or_init(|| DashMap::with_hasher(RandomState::new()));
+        *table
+            .entry(name.to_string())
+            .or_insert_with(|| Box::leak(name.to_string().into_boxed_str()))
+    }
+
+    /// Pulls the `service` field (a `&str`) out of a span's `Attributes`.
+    #[derive(Default)]
+    struct ServiceNameVisitor(Option<String>);
+
+    impl Visit for ServiceNameVisitor {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "service" {
+                self.0 = Some(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() == "service" && self.0.is_none() {
+                self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    /// Per-span bookkeeping stashed in the span's extensions on creation.
+    ///
+    /// `entered_at` is only `Some` while the span is currently entered on this
+    /// thread; `busy` accumulates the time between each enter/exit pair so a
+    /// span that's entered and exited multiple times (e.g. because its owning
+    /// future is polled in pieces) still reports one correct total instead of
+    /// counting idle time in between.
+    struct SpanTiming {
+        service: Option<&'static str>,
+        entered_at: Option<Instant>,
+        busy: Duration,
+    }
+
+    /// A [`tracing_subscriber::Layer`] that turns `di_resolve` spans (opened by
+    /// `Container::get`/`try_get`) into a per-type timing report.
+    ///
+    /// Install it alongside (or instead of) a formatting layer:
+    ///
+    /// ```rust,ignore
+    /// use dependency_injector::logging::ResolutionProfiler;
+    /// use tracing_subscriber::prelude::*;
+    ///
+    /// let profiler = std::sync::Arc::new(ResolutionProfiler::new());
+    /// tracing_subscriber::registry().with(profiler.clone()).init();
+    ///
+    /// // ... run the application, resolving services ...
+    ///
+    /// for entry in profiler.report() {
+    ///     println!("{}: {} resolves, {:?} total, {:?} max", entry.type_name, entry.count, entry.total, entry.max);
+    /// }
+    /// ```
+    ///
+    /// Only the `di_resolve` span emitted by this crate's own resolution path
+    /// is aggregated - other spans pass through untouched. A transient
+    /// factory still opens a fresh `di_resolve` span on every call, so its
+    /// entries accumulate under its concrete type name rather than appearing
+    /// as a single resolve.
+    #[derive(Debug, Default)]
+    pub struct ResolutionProfiler {
+        stats: DashMap<&'static str, RawStats, RandomState>,
+    }
+
+    impl ResolutionProfiler {
+        /// Create a profiler with no recorded resolves yet.
+        pub fn new() -> Self {
+            Self {
+                stats: DashMap::with_hasher(RandomState::new()),
+            }
+        }
+
+        /// Snapshot the current per-type stats, sorted by total time spent
+        /// resolving that type (slowest aggregate first). Sort by `.count`
+        /// yourself instead if you want the most *frequently* resolved types.
+        pub fn report(&self) -> Vec<ProfileEntry> {
+            let mut entries: Vec<ProfileEntry> = self
+                .stats
+                .iter()
+                .map(|entry| ProfileEntry {
+                    type_name: *entry.key(),
+                    count: entry.value().count.load(Ordering::Relaxed),
+                    total: Duration::from_nanos(entry.value().total_nanos.load(Ordering::Relaxed)),
+                    max: Duration::from_nanos(entry.value().max_nanos.load(Ordering::Relaxed)),
+                })
+                .collect();
+            entries.sort_by(|a, b| b.total.cmp(&a.total));
+            entries
+        }
+    }
+
+    impl<S> Layer<S> for ResolutionProfiler
+    where
+        S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            if attrs.metadata().name() != "di_resolve" {
+                return;
+            }
+            let mut visitor = ServiceNameVisitor::default();
+            attrs.record(&mut visitor);
+            let Some(span) = ctx.span(id) else { return };
+            span.extensions_mut().insert(SpanTiming {
+                service: visitor.0.map(|name| intern(&name)),
+                entered_at: None,
+                busy: Duration::ZERO,
+            });
+        }
+
+        fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+            let Some(span) = ctx.span(id) else { return };
+            let mut extensions = span.extensions_mut();
+            if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+                timing.entered_at = Some(Instant::now());
+            }
+        }
+
+        fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+            let Some(span) = ctx.span(id) else { return };
+            let mut extensions = span.extensions_mut();
+            if let Some(timing) = extensions.get_mut::<SpanTiming>() {
+                if let Some(entered_at) = timing.entered_at.take() {
+                    timing.busy += entered_at.elapsed();
+                }
+            }
+        }
+
+        fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+            let Some(span) = ctx.span(&id) else { return };
+            let extensions = span.extensions();
+            let Some(timing) = extensions.get::<SpanTiming>() else {
+                return;
+            };
+            if let Some(service) = timing.service {
+                self.stats.entry(service).or_default().record(timing.busy);
+            }
+        }
+    }
 }
 
+#[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+pub use profiler::{ProfileEntry, ResolutionProfiler};
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -360,4 +738,85 @@ mod tests {
         assert!(builder.with_line_number);
         assert_eq!(builder.target, Some("dependency_injector"));
     }
+
+    #[test]
+    fn test_builder_with_env_and_directives() {
+        let builder = LoggingBuilder::new()
+            .with_env()
+            .with_directive("dependency_injector::scope=trace")
+            .with_directive("dependency_injector::factory=debug");
+
+        assert!(builder.use_env);
+        assert_eq!(
+            builder.directives,
+            vec![
+                "dependency_injector::scope=trace".to_string(),
+                "dependency_injector::factory=debug".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_builder_capture_log_toggle() {
+        let builder = LoggingBuilder::new();
+        assert!(!builder.capture_log);
+        assert!(builder.capture_log().capture_log);
+    }
+
+    #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+    #[test]
+    fn test_logging_handle_set_level_reloads_filter() {
+        use tracing_subscriber::{EnvFilter, prelude::*, reload};
+
+        let (filter, reload_handle) = reload::Layer::new(EnvFilter::new("info"));
+        let subscriber = tracing_subscriber::registry().with(filter);
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let handle = LoggingHandle {
+            filter: reload_handle,
+        };
+        handle.set_level(Level::TRACE).unwrap();
+
+        let directive = handle.filter.with_current(|f| f.to_string()).unwrap();
+        assert_eq!(directive, "trace");
+    }
+
+    #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+    #[test]
+    fn test_resolution_profiler_aggregates_by_type_name() {
+        use std::sync::Arc;
+        use tracing_subscriber::prelude::*;
+
+        let profiler = Arc::new(ResolutionProfiler::new());
+        let subscriber = tracing_subscriber::registry().with(profiler.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..3 {
+                let span = tracing::span!(Level::DEBUG, "di_resolve", service = "crate::Widget");
+                let _guard = span.entered();
+            }
+        });
+
+        let report = profiler.report();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].type_name, "crate::Widget");
+        assert_eq!(report[0].count, 3);
+    }
+
+    #[cfg(any(feature = "logging-json", feature = "logging-pretty"))]
+    #[test]
+    fn test_resolution_profiler_ignores_unrelated_spans() {
+        use std::sync::Arc;
+        use tracing_subscriber::prelude::*;
+
+        let profiler = Arc::new(ResolutionProfiler::new());
+        let subscriber = tracing_subscriber::registry().with(profiler.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::span!(Level::DEBUG, "unrelated", service = "crate::Widget");
+            let _guard = span.entered();
+        });
+
+        assert!(profiler.report().is_empty());
+    }
 }
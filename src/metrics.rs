@@ -0,0 +1,219 @@
+//! Pluggable observability hooks for container resolution.
+//!
+//! `MetricsRecorder` lets you wire counters and latency histograms into
+//! whatever backend you use (Prometheus, a `metrics`-crate exporter, a log
+//! line, ...) without this crate depending on any particular one. Install a
+//! recorder with [`crate::Container::with_metrics`].
+
+use crate::Lifetime;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Observability hook for container resolution, registration, and scope
+/// creation.
+///
+/// All methods have no-op default implementations, so a recorder only needs
+/// to implement the callbacks it cares about.
+///
+/// To preserve the thread-local hot cache's fast path, `on_resolve` only
+/// fires for resolutions that miss the cache and fall through to storage -
+/// cache hits are not reported separately, since timing them would add
+/// overhead to the path they exist to avoid.
+pub trait MetricsRecorder: Send + Sync {
+    /// Called after a service was successfully resolved (cache misses only).
+    fn on_resolve(&self, type_name: &'static str, lifetime: Lifetime, duration: Duration) {
+        let _ = (type_name, lifetime, duration);
+    }
+
+    /// Called when a resolution failed because nothing was registered for the type.
+    fn on_miss(&self, type_name: &'static str) {
+        let _ = type_name;
+    }
+
+    /// Called after a service was registered.
+    fn on_register(&self, type_name: &'static str, lifetime: Lifetime) {
+        let _ = (type_name, lifetime);
+    }
+
+    /// Called after a new scope (child container) was created.
+    fn on_scope_created(&self) {}
+}
+
+// Blanket impl so an `Arc<impl MetricsRecorder>` can itself be passed to
+// `with_metrics`, letting callers keep an external handle to inspect later
+// (see `AtomicMetrics`'s doc example).
+impl<T: MetricsRecorder + ?Sized> MetricsRecorder for Arc<T> {
+    fn on_resolve(&self, type_name: &'static str, lifetime: Lifetime, duration: Duration) {
+        (**self).on_resolve(type_name, lifetime, duration);
+    }
+
+    fn on_miss(&self, type_name: &'static str) {
+        (**self).on_miss(type_name);
+    }
+
+    fn on_register(&self, type_name: &'static str, lifetime: Lifetime) {
+        (**self).on_register(type_name, lifetime);
+    }
+
+    fn on_scope_created(&self) {
+        (**self).on_scope_created();
+    }
+}
+
+/// A [`MetricsRecorder`] that does nothing. This is the default when no
+/// recorder has been installed.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopMetrics;
+
+impl MetricsRecorder for NoopMetrics {}
+
+/// A simple atomic-counter [`MetricsRecorder`] exposing per-[`Lifetime`]
+/// resolution counts and a hit/miss ratio.
+///
+/// # Example
+///
+/// ```rust
+/// use dependency_injector::Container;
+/// use dependency_injector::metrics::AtomicMetrics;
+/// use std::sync::Arc;
+///
+/// #[derive(Clone)]
+/// struct Config;
+///
+/// let metrics = Arc::new(AtomicMetrics::new());
+/// let container = Container::new().with_metrics(metrics.clone());
+/// container.singleton(Config);
+///
+/// let _ = container.get::<Config>().unwrap();
+/// assert_eq!(metrics.misses(), 0);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicMetrics {
+    singleton_resolves: AtomicU64,
+    lazy_resolves: AtomicU64,
+    transient_resolves: AtomicU64,
+    scoped_resolves: AtomicU64,
+    pooled_resolves: AtomicU64,
+    reloadable_resolves: AtomicU64,
+    misses: AtomicU64,
+    registrations: AtomicU64,
+    scopes_created: AtomicU64,
+}
+
+impl AtomicMetrics {
+    /// Create a new, zeroed set of counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn counter_for(&self, lifetime: Lifetime) -> &AtomicU64 {
+        match lifetime {
+            Lifetime::Singleton => &self.singleton_resolves,
+            Lifetime::Lazy => &self.lazy_resolves,
+            Lifetime::Transient => &self.transient_resolves,
+            Lifetime::Scoped => &self.scoped_resolves,
+            Lifetime::Pooled => &self.pooled_resolves,
+            Lifetime::Reloadable => &self.reloadable_resolves,
+        }
+    }
+
+    /// Number of successful resolutions recorded for a specific lifetime.
+    pub fn resolves(&self, lifetime: Lifetime) -> u64 {
+        self.counter_for(lifetime).load(Ordering::Relaxed)
+    }
+
+    /// Total successful resolutions across all lifetimes.
+    pub fn hits(&self) -> u64 {
+        self.singleton_resolves.load(Ordering::Relaxed)
+            + self.lazy_resolves.load(Ordering::Relaxed)
+            + self.transient_resolves.load(Ordering::Relaxed)
+            + self.scoped_resolves.load(Ordering::Relaxed)
+            + self.pooled_resolves.load(Ordering::Relaxed)
+            + self.reloadable_resolves.load(Ordering::Relaxed)
+    }
+
+    /// Total failed resolutions (service not registered).
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Hit ratio in `[0.0, 1.0]`. Returns `1.0` if nothing has been resolved yet.
+    pub fn hit_ratio(&self) -> f64 {
+        let hits = self.hits();
+        let total = hits + self.misses();
+        if total == 0 {
+            1.0
+        } else {
+            hits as f64 / total as f64
+        }
+    }
+
+    /// Total services registered.
+    pub fn registrations(&self) -> u64 {
+        self.registrations.load(Ordering::Relaxed)
+    }
+
+    /// Total scopes created.
+    pub fn scopes_created(&self) -> u64 {
+        self.scopes_created.load(Ordering::Relaxed)
+    }
+}
+
+impl MetricsRecorder for AtomicMetrics {
+    fn on_resolve(&self, _type_name: &'static str, lifetime: Lifetime, _duration: Duration) {
+        self.counter_for(lifetime).fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_miss(&self, _type_name: &'static str) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_register(&self, _type_name: &'static str, _lifetime: Lifetime) {
+        self.registrations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_scope_created(&self) {
+        self.scopes_created.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noop_metrics_does_nothing() {
+        let metrics = NoopMetrics;
+        metrics.on_resolve("X", Lifetime::Singleton, Duration::ZERO);
+        metrics.on_miss("X");
+        metrics.on_register("X", Lifetime::Singleton);
+        metrics.on_scope_created();
+    }
+
+    #[test]
+    fn test_atomic_metrics_counts_by_lifetime() {
+        let metrics = AtomicMetrics::new();
+        metrics.on_resolve("A", Lifetime::Singleton, Duration::ZERO);
+        metrics.on_resolve("B", Lifetime::Transient, Duration::ZERO);
+        metrics.on_resolve("B", Lifetime::Transient, Duration::ZERO);
+        metrics.on_miss("C");
+
+        assert_eq!(metrics.resolves(Lifetime::Singleton), 1);
+        assert_eq!(metrics.resolves(Lifetime::Transient), 2);
+        assert_eq!(metrics.hits(), 3);
+        assert_eq!(metrics.misses(), 1);
+        assert_eq!(metrics.hit_ratio(), 0.75);
+    }
+
+    #[test]
+    fn test_registrations_and_scopes() {
+        let metrics = AtomicMetrics::new();
+        metrics.on_register("A", Lifetime::Singleton);
+        metrics.on_register("B", Lifetime::Transient);
+        metrics.on_scope_created();
+
+        assert_eq!(metrics.registrations(), 2);
+        assert_eq!(metrics.scopes_created(), 1);
+    }
+}
@@ -0,0 +1,206 @@
+//! In-place-recycled object pool for `Injectable` types.
+//!
+//! [`crate::ScopePool`] recycles whole scopes; [`Container::pooled`]/
+//! [`crate::PoolGuard`] recycles instances but bounds how many exist at once
+//! and blocks checkout past that bound. Neither fits the common case of an
+//! expensive-to-allocate value (a `Vec` scratch buffer, a `String`, a
+//! serializer) that just needs its *contents* reset between uses - the
+//! allocation itself is what's worth keeping. [`ObjectPool`] is that: a
+//! free-list of `T` values, `Reset` in place and handed back out, with no
+//! bound and no blocking - an empty free-list just means a fresh value gets
+//! built.
+
+use crate::Injectable;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+
+/// Opt-in in-place reset for a pooled value.
+///
+/// Implemented by types [`ObjectPool`] recycles - clearing a `Vec`'s length
+/// while keeping its backing allocation, resetting a scratch buffer's
+/// cursor, clearing a `String` without releasing its capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// use dependency_injector::Reset;
+///
+/// struct Buffer(Vec<u8>);
+///
+/// impl Reset for Buffer {
+///     fn reset(&mut self) {
+///         self.0.clear();
+///     }
+/// }
+/// ```
+pub trait Reset {
+    /// Restore this value to a clean, reusable state without dropping it.
+    fn reset(&mut self);
+}
+
+struct ObjectPoolState<T> {
+    idle: Vec<T>,
+}
+
+/// A free-list pool of in-place-recycled `T` values.
+///
+/// Created via [`Container::object_pool`]/[`Container::object_pool_with`].
+/// [`ObjectPool::checkout`] returns a [`Pooled<T>`] - a `DerefMut` handle
+/// that, on drop, calls [`Reset::reset`] and returns the value to the
+/// free-list instead of dropping it.
+pub struct ObjectPool<T: Injectable + Reset> {
+    factory: Box<dyn Fn() -> T + Send + Sync>,
+    state: Mutex<ObjectPoolState<T>>,
+}
+
+impl<T: Injectable + Reset> ObjectPool<T> {
+    /// Create an empty pool - a checkout builds a fresh value via `factory`
+    /// whenever no returned value is idle in the free-list.
+    pub(crate) fn new<F>(factory: F) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self::with_capacity(factory, 0)
+    }
+
+    /// Create a pool with `capacity` values pre-built via `factory`.
+    pub(crate) fn with_capacity<F>(factory: F, capacity: usize) -> Self
+    where
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        let factory: Box<dyn Fn() -> T + Send + Sync> = Box::new(factory);
+        let idle = (0..capacity).map(|_| factory()).collect();
+
+        Self {
+            factory,
+            state: Mutex::new(ObjectPoolState { idle }),
+        }
+    }
+
+    /// Check out a value - reused from the free-list if one's idle,
+    /// otherwise freshly built. Never blocks.
+    pub fn checkout(self: &Arc<Self>) -> Pooled<T> {
+        let value = self
+            .state
+            .lock()
+            .unwrap()
+            .idle
+            .pop()
+            .unwrap_or_else(|| (self.factory)());
+
+        Pooled {
+            value: Some(value),
+            pool: Arc::clone(self),
+        }
+    }
+
+    /// Number of values currently idle in the free-list.
+    pub fn idle_count(&self) -> usize {
+        self.state.lock().unwrap().idle.len()
+    }
+}
+
+/// An in-place-recycled checkout from an [`ObjectPool`].
+///
+/// Derefs to `T`. On drop, resets the value via [`Reset::reset`] and returns
+/// it to the pool's free-list rather than dropping it.
+pub struct Pooled<T: Injectable + Reset> {
+    // `None` only between `Drop::drop` starting and finishing; always
+    // `Some` for the guard's entire externally-observable lifetime.
+    value: Option<T>,
+    pool: Arc<ObjectPool<T>>,
+}
+
+impl<T: Injectable + Reset> Deref for Pooled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("Pooled value taken before drop")
+    }
+}
+
+impl<T: Injectable + Reset> DerefMut for Pooled<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("Pooled value taken before drop")
+    }
+}
+
+impl<T: Injectable + Reset> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        if let Some(mut value) = self.value.take() {
+            value.reset();
+            self.pool.state.lock().unwrap().idle.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Default)]
+    struct Buffer(Vec<u8>);
+
+    impl Reset for Buffer {
+        fn reset(&mut self) {
+            self.0.clear();
+        }
+    }
+
+    #[test]
+    fn test_checkout_builds_fresh_value_when_free_list_empty() {
+        let pool = Arc::new(ObjectPool::new(Buffer::default));
+        assert_eq!(pool.idle_count(), 0);
+
+        let mut buf = pool.checkout();
+        buf.0.extend_from_slice(b"hello");
+        assert_eq!(&buf.0, b"hello");
+    }
+
+    #[test]
+    fn test_drop_resets_and_returns_value_reusing_its_allocation() {
+        let pool = Arc::new(ObjectPool::new(Buffer::default));
+
+        let mut buf = pool.checkout();
+        buf.0.reserve(64);
+        buf.0.extend_from_slice(b"scratch data");
+        let backing_ptr = buf.0.as_ptr();
+        drop(buf);
+
+        assert_eq!(pool.idle_count(), 1);
+
+        let buf2 = pool.checkout();
+        // Same allocation reused - empty after reset, not rebuilt.
+        assert!(buf2.0.is_empty());
+        assert_eq!(buf2.0.as_ptr(), backing_ptr);
+    }
+
+    #[test]
+    fn test_with_capacity_preallocates_idle_values() {
+        static BUILT: AtomicU32 = AtomicU32::new(0);
+
+        struct Counted;
+
+        impl Reset for Counted {
+            fn reset(&mut self) {}
+        }
+
+        let pool: Arc<ObjectPool<Counted>> = Arc::new(ObjectPool::with_capacity(
+            || {
+                BUILT.fetch_add(1, Ordering::SeqCst);
+                Counted
+            },
+            3,
+        ));
+
+        assert_eq!(BUILT.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.idle_count(), 3);
+
+        let c = pool.checkout();
+        assert_eq!(BUILT.load(Ordering::SeqCst), 3);
+        assert_eq!(pool.idle_count(), 2);
+        drop(c);
+        assert_eq!(pool.idle_count(), 3);
+    }
+}
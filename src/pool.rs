@@ -0,0 +1,179 @@
+//! Bounded object pool lifetime.
+//!
+//! A pooled registration (`Container::pooled`/`pooled_with_recycle`) differs
+//! from every other lifetime in this crate: the resolved value isn't a
+//! shared `Arc<T>`, it's an exclusive checkout that must be handed back when
+//! the caller is done with it. [`PoolGuard`] is that checkout - a `DerefMut`
+//! handle that returns its instance to the pool on `Drop`, the same RAII
+//! shape `std::sync::MutexGuard` uses for a lock.
+
+use crate::Injectable;
+use std::any::Any;
+use std::collections::VecDeque;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+type RecycleFn = Box<dyn Fn(&mut (dyn Any + Send)) -> bool + Send + Sync>;
+
+struct PoolState {
+    /// Idle instances available for checkout.
+    idle: VecDeque<Box<dyn Any + Send>>,
+    /// Count of instances currently either idle or checked out - bounded by
+    /// `max_size`. Distinct from `idle.len()`, which only tracks the idle half.
+    live: usize,
+}
+
+/// Type-erased bounded pool backing a `Pooled` registration.
+///
+/// Checkout blocks (optionally with a timeout) once `max_size` instances are
+/// live, rather than creating unbounded extras - the point of pooling a
+/// resource is capping how many exist at once (e.g. DB connections).
+pub(crate) struct PooledFactory {
+    factory: Box<dyn Fn() -> Box<dyn Any + Send> + Send + Sync>,
+    recycle: Option<RecycleFn>,
+    max_size: usize,
+    state: Mutex<PoolState>,
+    /// Signaled whenever an instance is checked back in, so a blocked
+    /// `checkout` can wake up and recheck the idle queue.
+    available: Condvar,
+}
+
+impl PooledFactory {
+    /// Create a pool whose instances are never validated on checkout - every
+    /// idle instance is handed back out as-is.
+    pub(crate) fn new<T, F>(factory: F, max_size: usize) -> Self
+    where
+        T: Injectable,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(move || Box::new(factory()) as Box<dyn Any + Send>),
+            recycle: None,
+            max_size,
+            state: Mutex::new(PoolState {
+                idle: VecDeque::new(),
+                live: 0,
+            }),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Create a pool whose idle instances are passed through `recycle` before
+    /// being handed out again. Returning `false` discards the instance (and
+    /// frees its slot towards `max_size`) instead of checking it out.
+    pub(crate) fn with_recycle<T, F, R>(factory: F, max_size: usize, recycle: R) -> Self
+    where
+        T: Injectable,
+        F: Fn() -> T + Send + Sync + 'static,
+        R: Fn(&mut T) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(move || Box::new(factory()) as Box<dyn Any + Send>),
+            recycle: Some(Box::new(move |any| {
+                recycle(any.downcast_mut::<T>().expect("PooledFactory type mismatch"))
+            })),
+            max_size,
+            state: Mutex::new(PoolState {
+                idle: VecDeque::new(),
+                live: 0,
+            }),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Check out an instance, creating a fresh one if under `max_size`,
+    /// reusing an idle one (validated via `recycle` if configured), or
+    /// blocking until one is available - for up to `timeout`, or
+    /// indefinitely if `None`.
+    ///
+    /// Returns `None` only if `timeout` elapses with nothing available.
+    pub(crate) fn checkout(&self, timeout: Option<Duration>) -> Option<Box<dyn Any + Send>> {
+        let deadline = timeout.map(|d| Instant::now() + d);
+        let mut state = self.state.lock().unwrap();
+
+        loop {
+            while let Some(mut instance) = state.idle.pop_front() {
+                if let Some(recycle) = &self.recycle {
+                    if recycle(&mut *instance) {
+                        return Some(instance);
+                    }
+                    state.live -= 1;
+                    continue;
+                }
+                return Some(instance);
+            }
+
+            if state.live < self.max_size {
+                state.live += 1;
+                drop(state);
+                return Some((self.factory)());
+            }
+
+            let Some(deadline) = deadline else {
+                state = self.available.wait(state).unwrap();
+                continue;
+            };
+
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            let (guard, timeout_result) = self.available.wait_timeout(state, deadline - now).unwrap();
+            state = guard;
+            if timeout_result.timed_out() && state.idle.is_empty() && state.live >= self.max_size {
+                return None;
+            }
+        }
+    }
+
+    /// Return a checked-out instance to the idle queue and wake one waiter.
+    pub(crate) fn checkin(&self, instance: Box<dyn Any + Send>) {
+        self.state.lock().unwrap().idle.push_back(instance);
+        self.available.notify_one();
+    }
+}
+
+/// An exclusive checkout from a pooled registration, returned by
+/// `Container::get_pooled`/`get_pooled_timeout`.
+///
+/// Derefs to `T`. Returns the instance to its pool (for reuse or recycling)
+/// when dropped, rather than destroying it - the pool, not the caller, owns
+/// the instance's lifetime.
+pub struct PoolGuard<T: Injectable> {
+    // `None` only between `Drop::drop` starting and finishing; always `Some`
+    // for the guard's entire externally-observable lifetime.
+    value: Option<T>,
+    pool: Arc<PooledFactory>,
+}
+
+impl<T: Injectable> PoolGuard<T> {
+    pub(crate) fn new(value: T, pool: Arc<PooledFactory>) -> Self {
+        Self {
+            value: Some(value),
+            pool,
+        }
+    }
+}
+
+impl<T: Injectable> Deref for PoolGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("PoolGuard value taken before drop")
+    }
+}
+
+impl<T: Injectable> DerefMut for PoolGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("PoolGuard value taken before drop")
+    }
+}
+
+impl<T: Injectable> Drop for PoolGuard<T> {
+    fn drop(&mut self) {
+        if let Some(value) = self.value.take() {
+            self.pool.checkin(Box::new(value));
+        }
+    }
+}
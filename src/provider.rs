@@ -43,6 +43,37 @@ pub trait Injectable: Send + Sync + 'static {
 // Blanket implementation - everything that's Send + Sync + 'static is Injectable
 impl<T: Send + Sync + 'static> Injectable for T {}
 
+/// Opt-in teardown for a service that holds a resource needing explicit
+/// cleanup - a connection, a file handle, a pooled checkout.
+///
+/// Implement this and register with `Container::register_disposable`/
+/// `ScopedContainer::register_disposable` to have `dispose` run when the
+/// owning scope is dropped or cleared, alongside any closures registered via
+/// `register_with_dispose`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dependency_injector::{Container, Disposable};
+///
+/// struct Connection;
+///
+/// impl Disposable for Connection {
+///     fn dispose(&self) {
+///         // close the connection
+///     }
+/// }
+///
+/// let container = Container::new();
+/// container.register_disposable(Connection);
+/// ```
+pub trait Disposable: Send + Sync {
+    /// Tear down this instance. Called at most once, in reverse
+    /// registration order relative to every other disposer on the same
+    /// scope, when that scope is dropped or `clear()`'d.
+    fn dispose(&self);
+}
+
 /// Backward compatibility alias
 pub trait Provider: Injectable {}
 impl<T: Injectable> Provider for T {}
@@ -62,6 +93,38 @@ pub enum Lifetime {
 
     /// One instance per scope
     Scoped,
+
+    /// Bounded pool of reusable-but-not-shared instances, checked out via
+    /// `Container::get_pooled` and returned to the pool when the
+    /// `PoolGuard` drops.
+    Pooled,
+
+    /// Single instance, shared across all resolves, that can be atomically
+    /// swapped out via `Container::replace` without locking the container.
+    Reloadable,
+}
+
+impl Lifetime {
+    /// Short, stable name for this lifetime - used wherever a `&'static str`
+    /// is needed instead of the `Debug` representation (tracing span fields,
+    /// log lines).
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Singleton => "singleton",
+            Self::Lazy => "lazy",
+            Self::Transient => "transient",
+            Self::Scoped => "scoped",
+            Self::Pooled => "pooled",
+            Self::Reloadable => "reloadable",
+        }
+    }
+}
+
+impl std::fmt::Display for Lifetime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Registration information for a provider (used by module system)
@@ -140,4 +203,13 @@ macro_rules! provider {
             },
         }
     };
+    (bind $trait:ty, $concrete:ty, $coerce:expr) => {
+        $crate::ProviderRegistration {
+            type_id: std::any::TypeId::of::<$concrete>(),
+            type_name: std::any::type_name::<$concrete>(),
+            register_fn: |container| {
+                container.bind::<$trait, $concrete>($coerce);
+            },
+        }
+    };
 }
@@ -0,0 +1,421 @@
+//! Config-driven container composition.
+//!
+//! Everything elsewhere in this crate wires a [`Container`] from Rust code. This
+//! module adds a second path: a [`ServiceRegistry`] maps a string `type` tag to a
+//! [`ServiceBuilder`] closure, and [`Container::build_from_config`] walks a
+//! deserialized list of `{ name, type, params }` entries, looking each one up and
+//! registering the result. This is how an operator swaps an in-memory cache for a
+//! network-backed one via a config file without recompiling.
+//!
+//! Requires the `config` feature.
+
+use crate::{Container, DiError, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A type-erased constructor: takes an entry's `params` and produces the boxed
+/// instance to register, or an error if `params` didn't match what the builder
+/// expects.
+pub type ServiceBuilder = Box<dyn Fn(Value) -> Result<Arc<dyn Any + Send + Sync>> + Send + Sync>;
+
+/// One entry in a service-config file.
+///
+/// ```json
+/// { "name": "primary-cache", "type": "memory-cache", "params": { "capacity": 1024 } }
+/// ```
+///
+/// `type` selects which [`ServiceBuilder`] runs; `name` is carried through only
+/// for logging/diagnostics since registration itself is keyed by the builder's
+/// concrete type, not by name.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServiceConfigEntry {
+    /// Human-readable name for this entry, used only for diagnostics.
+    pub name: String,
+    /// The registry tag selecting which [`ServiceBuilder`] to invoke.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Builder-specific parameters, forwarded to the matching builder untouched.
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Maps string `type` tags to [`ServiceBuilder`]s for [`Container::build_from_config`].
+///
+/// # Example
+///
+/// ```rust
+/// use dependency_injector::registry::ServiceRegistry;
+/// use dependency_injector::Container;
+/// use std::sync::Arc;
+///
+/// #[derive(Clone)]
+/// struct MemoryCache { capacity: u64 }
+///
+/// let registry = ServiceRegistry::new().register("memory-cache", |params| {
+///     let capacity = params.get("capacity").and_then(|v| v.as_u64()).unwrap_or(0);
+///     Ok(Arc::new(MemoryCache { capacity }) as Arc<dyn std::any::Any + Send + Sync>)
+/// });
+///
+/// let container = Container::new();
+/// container
+///     .build_from_config(&registry, r#"[{"name": "primary", "type": "memory-cache", "params": {"capacity": 1024}}]"#)
+///     .unwrap();
+///
+/// assert_eq!(container.get::<MemoryCache>().unwrap().capacity, 1024);
+/// ```
+#[derive(Default)]
+pub struct ServiceRegistry {
+    builders: HashMap<String, ServiceBuilder>,
+}
+
+impl ServiceRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a builder under `type_tag`. Returns `self` for chaining.
+    pub fn register(
+        mut self,
+        type_tag: impl Into<String>,
+        builder: impl Fn(Value) -> Result<Arc<dyn Any + Send + Sync>> + Send + Sync + 'static,
+    ) -> Self {
+        self.builders.insert(type_tag.into(), Box::new(builder));
+        self
+    }
+
+    /// Look up the builder registered for `type_tag`, if any.
+    pub(crate) fn get(&self, type_tag: &str) -> Option<&ServiceBuilder> {
+        self.builders.get(type_tag)
+    }
+}
+
+impl std::fmt::Debug for ServiceRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceRegistry")
+            .field("type_tags", &self.builders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+/// Parse a JSON array of [`ServiceConfigEntry`] values.
+pub(crate) fn parse_entries(config: &str) -> Result<Vec<ServiceConfigEntry>> {
+    serde_json::from_str(config)
+        .map_err(|e| DiError::Internal(format!("invalid service config: {e}")))
+}
+
+// =============================================================================
+// Typed Config Composition
+// =============================================================================
+
+/// Handed to every [`ConfigRegistry`] builder alongside its deserialized
+/// config struct, giving it access to whatever was already composed earlier
+/// in the same config file - e.g. a `"web-server"` entry pulling in the
+/// `"postgres"` entry composed above it.
+pub struct CompositionContext<'a> {
+    container: &'a Container,
+}
+
+impl<'a> CompositionContext<'a> {
+    /// Create a context wrapping `container` - used internally by
+    /// `Container::compose_from_config`.
+    pub(crate) fn new(container: &'a Container) -> Self {
+        Self { container }
+    }
+
+    /// The container being composed into, for resolving already-registered
+    /// dependencies mid-composition.
+    pub fn container(&self) -> &Container {
+        self.container
+    }
+}
+
+/// A type-erased constructor for [`ConfigRegistry`]: deserializes an entry's
+/// `params` into the builder's own config struct and hands it, along with a
+/// [`CompositionContext`], to the registered closure.
+type ConfigBuilder = Box<dyn Fn(Value, &CompositionContext) -> Result<Arc<dyn Any + Send + Sync>> + Send + Sync>;
+
+/// Maps string `type` tags to builders that each take their own
+/// `Deserialize`-able config struct, rather than raw [`serde_json::Value`].
+///
+/// This is the typed counterpart to [`ServiceRegistry`]: where a
+/// `ServiceRegistry` builder pulls individual fields out of `params` by hand
+/// (`params.get("capacity").and_then(...)`), a `ConfigRegistry` builder
+/// declares a `#[derive(Deserialize)] struct FooConfig { .. }` and lets serde
+/// do the field extraction, inspired by tvix-castore's composition module.
+/// Drive it with [`TypedBuilder::from_config`](crate::typed::TypedBuilder::from_config).
+///
+/// # Example
+///
+/// ```rust
+/// use dependency_injector::registry::ConfigRegistry;
+/// use dependency_injector::typed::TypedBuilder;
+/// use serde::Deserialize;
+/// use std::sync::Arc;
+///
+/// #[derive(Deserialize)]
+/// struct PostgresConfig {
+///     host: String,
+/// }
+///
+/// #[derive(Clone)]
+/// struct Postgres {
+///     host: String,
+/// }
+///
+/// let registry = ConfigRegistry::new().register("postgres", |cfg: PostgresConfig, _ctx| {
+///     Arc::new(Postgres { host: cfg.host }) as Arc<dyn std::any::Any + Send + Sync>
+/// });
+///
+/// let container = TypedBuilder::from_config(
+///     &registry,
+///     r#"[{"name": "db", "type": "postgres", "params": {"host": "localhost"}}]"#,
+/// )
+/// .unwrap()
+/// .build_dynamic();
+///
+/// assert_eq!(container.get::<Postgres>().unwrap().host, "localhost");
+/// ```
+#[derive(Default)]
+pub struct ConfigRegistry {
+    builders: HashMap<String, ConfigBuilder>,
+}
+
+impl ConfigRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a builder under `type_tag`, deserializing each matching
+    /// entry's `params` into `Cfg` before calling `builder`.
+    ///
+    /// Returns `self` for chaining.
+    pub fn register<Cfg>(
+        mut self,
+        type_tag: impl Into<String>,
+        builder: impl Fn(Cfg, &CompositionContext) -> Arc<dyn Any + Send + Sync> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cfg: serde::de::DeserializeOwned + 'static,
+    {
+        self.builders.insert(
+            type_tag.into(),
+            Box::new(move |params, ctx| {
+                let cfg: Cfg = serde_json::from_value(params)
+                    .map_err(|e| DiError::Internal(format!("invalid config for type `{}`: {e}", std::any::type_name::<Cfg>())))?;
+                Ok(builder(cfg, ctx))
+            }),
+        );
+        self
+    }
+
+    /// Register a builder under `type_tag` whose config is a single URL
+    /// string (`"params": "postgres://localhost/app"`) instead of an object,
+    /// via `Cfg: TryFrom<url::Url>`. This is the shorthand for services that
+    /// are fully described by one connection string.
+    ///
+    /// Returns `self` for chaining.
+    pub fn register_from_url<Cfg>(
+        mut self,
+        type_tag: impl Into<String>,
+        builder: impl Fn(Cfg, &CompositionContext) -> Arc<dyn Any + Send + Sync> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Cfg: TryFrom<url::Url> + 'static,
+        Cfg::Error: std::fmt::Display,
+    {
+        self.builders.insert(
+            type_tag.into(),
+            Box::new(move |params, ctx| {
+                let raw = params
+                    .as_str()
+                    .ok_or_else(|| DiError::Internal("expected params to be a URL string".into()))?;
+                let url = url::Url::parse(raw).map_err(|e| DiError::Internal(format!("invalid URL `{raw}`: {e}")))?;
+                let cfg = Cfg::try_from(url).map_err(|e| DiError::Internal(format!("invalid URL config: {e}")))?;
+                Ok(builder(cfg, ctx))
+            }),
+        );
+        self
+    }
+
+    /// Look up the builder registered for `type_tag`, if any.
+    pub(crate) fn get(&self, type_tag: &str) -> Option<&ConfigBuilder> {
+        self.builders.get(type_tag)
+    }
+}
+
+impl std::fmt::Debug for ConfigRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigRegistry")
+            .field("type_tags", &self.builders.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Container;
+
+    #[derive(Clone)]
+    struct MemoryCache {
+        capacity: u64,
+    }
+
+    fn test_registry() -> ServiceRegistry {
+        ServiceRegistry::new().register("memory-cache", |params| {
+            let capacity = params.get("capacity").and_then(|v| v.as_u64()).unwrap_or(0);
+            Ok(Arc::new(MemoryCache { capacity }) as Arc<dyn Any + Send + Sync>)
+        })
+    }
+
+    #[test]
+    fn test_build_from_config_registers_matching_builder() {
+        let container = Container::new();
+        let registry = test_registry();
+
+        container
+            .build_from_config(
+                &registry,
+                r#"[{"name": "primary", "type": "memory-cache", "params": {"capacity": 1024}}]"#,
+            )
+            .unwrap();
+
+        assert_eq!(container.get::<MemoryCache>().unwrap().capacity, 1024);
+    }
+
+    #[test]
+    fn test_build_from_config_fails_on_unknown_type_tag() {
+        let container = Container::new();
+        let registry = test_registry();
+
+        let result = container.build_from_config(
+            &registry,
+            r#"[{"name": "primary", "type": "redis-cache", "params": {}}]"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_build_from_config_fails_on_invalid_json() {
+        let container = Container::new();
+        let registry = test_registry();
+
+        assert!(container.build_from_config(&registry, "not json").is_err());
+    }
+
+    #[derive(serde::Deserialize)]
+    struct PostgresConfig {
+        host: String,
+    }
+
+    #[derive(Clone)]
+    struct Postgres {
+        host: String,
+    }
+
+    #[derive(Clone)]
+    struct WebServer {
+        db_host: String,
+    }
+
+    fn test_config_registry() -> ConfigRegistry {
+        ConfigRegistry::new()
+            .register("postgres", |cfg: PostgresConfig, _ctx| {
+                Arc::new(Postgres { host: cfg.host }) as Arc<dyn Any + Send + Sync>
+            })
+            .register("web-server", |_cfg: serde_json::Value, ctx| {
+                let db = ctx.container().get::<Postgres>().unwrap();
+                Arc::new(WebServer {
+                    db_host: db.host.clone(),
+                }) as Arc<dyn Any + Send + Sync>
+            })
+    }
+
+    #[test]
+    fn test_compose_from_config_deserializes_into_builders_own_config_type() {
+        let container = Container::new();
+        let registry = test_config_registry();
+
+        container
+            .compose_from_config(
+                &registry,
+                r#"[{"name": "db", "type": "postgres", "params": {"host": "localhost"}}]"#,
+            )
+            .unwrap();
+
+        assert_eq!(container.get::<Postgres>().unwrap().host, "localhost");
+    }
+
+    #[test]
+    fn test_compose_from_config_context_resolves_earlier_entries() {
+        let container = Container::new();
+        let registry = test_config_registry();
+
+        container
+            .compose_from_config(
+                &registry,
+                r#"[
+                    {"name": "db", "type": "postgres", "params": {"host": "db.internal"}},
+                    {"name": "web", "type": "web-server", "params": {}}
+                ]"#,
+            )
+            .unwrap();
+
+        assert_eq!(container.get::<WebServer>().unwrap().db_host, "db.internal");
+    }
+
+    #[test]
+    fn test_compose_from_config_fails_on_mismatched_config_shape() {
+        let container = Container::new();
+        let registry = test_config_registry();
+
+        let result = container.compose_from_config(
+            &registry,
+            r#"[{"name": "db", "type": "postgres", "params": {"wrong_field": 1}}]"#,
+        );
+
+        assert!(result.is_err());
+    }
+
+    struct RedisConfig {
+        url: url::Url,
+    }
+
+    impl TryFrom<url::Url> for RedisConfig {
+        type Error = std::convert::Infallible;
+
+        fn try_from(url: url::Url) -> Result<Self, Self::Error> {
+            Ok(RedisConfig { url })
+        }
+    }
+
+    #[derive(Clone)]
+    struct Redis {
+        host: String,
+    }
+
+    #[test]
+    fn test_register_from_url_parses_single_url_string_param() {
+        let container = Container::new();
+        let registry = ConfigRegistry::new().register_from_url("redis", |cfg: RedisConfig, _ctx| {
+            Arc::new(Redis {
+                host: cfg.url.host_str().unwrap_or_default().to_string(),
+            }) as Arc<dyn Any + Send + Sync>
+        });
+
+        container
+            .compose_from_config(
+                &registry,
+                r#"[{"name": "cache", "type": "redis", "params": "redis://localhost:6379"}]"#,
+            )
+            .unwrap();
+
+        assert_eq!(container.get::<Redis>().unwrap().host, "localhost");
+    }
+}
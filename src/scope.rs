@@ -177,12 +177,93 @@ impl ScopedContainer {
         self.container.register_factory(factory);
     }
 
+    /// Register a teardown closure, with no service instance attached, that
+    /// runs when this scope is dropped or `clear()`'d. See `Container::on_dispose`.
+    #[inline]
+    pub fn on_dispose<F>(&self, dispose: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.container.on_dispose(dispose);
+    }
+
+    /// Register a singleton with a dispose closure that runs when this
+    /// scope is dropped or `clear()`'d. See `Container::register_with_dispose`.
+    #[inline]
+    pub fn register_with_dispose<T, F>(&self, instance: T, dispose: F)
+    where
+        T: Injectable,
+        F: Fn(&Arc<T>) + Send + Sync + 'static,
+    {
+        self.container.register_with_dispose(instance, dispose);
+    }
+
+    /// Register a singleton whose `Disposable::dispose` runs when this
+    /// scope is dropped or `clear()`'d. See `Container::register_disposable`.
+    #[inline]
+    pub fn register_disposable<T>(&self, instance: T)
+    where
+        T: Injectable + crate::Disposable,
+    {
+        self.container.register_disposable(instance);
+    }
+
+    /// Register a bounded pool of instances in this scope. See
+    /// `Container::pooled`.
+    #[inline]
+    pub fn pooled<T, F>(&self, factory: F, max_size: usize)
+    where
+        T: Injectable,
+        F: Fn() -> T + Send + Sync + 'static,
+    {
+        self.container.pooled(factory, max_size);
+    }
+
+    /// Register a bounded pool of instances in this scope, validating each
+    /// idle instance via `recycle` before it's checked out again. See
+    /// `Container::pooled_with_recycle`.
+    #[inline]
+    pub fn pooled_with_recycle<T, F, R>(&self, factory: F, max_size: usize, recycle: R)
+    where
+        T: Injectable,
+        F: Fn() -> T + Send + Sync + 'static,
+        R: Fn(&mut T) -> bool + Send + Sync + 'static,
+    {
+        self.container.pooled_with_recycle(factory, max_size, recycle);
+    }
+
+    /// Check out an instance from a pooled registration in this scope or a
+    /// parent. See `Container::get_pooled`.
+    #[inline]
+    pub fn get_pooled<T: Injectable>(&self) -> Result<crate::PoolGuard<T>> {
+        self.container.get_pooled::<T>()
+    }
+
+    /// Check out an instance from a pooled registration in this scope or a
+    /// parent, giving up after `timeout`. See `Container::get_pooled_timeout`.
+    #[inline]
+    pub fn get_pooled_timeout<T: Injectable>(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<Option<crate::PoolGuard<T>>> {
+        self.container.get_pooled_timeout::<T>(timeout)
+    }
+
     /// Resolve a service from this scope or parent scopes.
     #[inline]
     pub fn get<T: Injectable>(&self) -> Result<Arc<T>> {
         self.container.get::<T>()
     }
 
+    /// Get a cloneable, `Send + Sync` handle that resolves `T` against this
+    /// scope on demand, even after this `ScopedContainer` is dropped - it
+    /// surfaces `DiError::ParentDropped` from `Resolver::get` instead of
+    /// panicking once the scope is actually gone. See `Container::resolver`.
+    #[inline]
+    pub fn resolver<T: Injectable>(&self) -> crate::Resolver<T> {
+        self.container.resolver::<T>()
+    }
+
     /// Alias for get.
     #[inline]
     pub fn resolve<T: Injectable>(&self) -> Result<Arc<T>> {
@@ -195,10 +276,57 @@ impl ScopedContainer {
         self.container.try_get::<T>()
     }
 
-    /// Alias for try_get.
+    /// Resolve a service registered via `try_lazy`/`try_transient` on this
+    /// scope or a parent. See `Container::try_resolve`.
+    #[inline]
+    pub fn try_resolve<T: Injectable>(&self) -> std::result::Result<Arc<T>, crate::ResolveError> {
+        self.container.try_resolve::<T>()
+    }
+
+    /// Bind a concrete type to a trait interface in this scope. See
+    /// `Container::bind`.
+    ///
+    /// Binding at scope level lets per-request overrides swap in a different
+    /// implementation than the one bound on the parent container, since
+    /// interface lookups walk the scope chain the same way `get` does.
+    #[inline]
+    pub fn bind<Trait, Concrete>(&self, coerce: impl Fn(Arc<Concrete>) -> Arc<Trait> + Send + Sync + 'static)
+    where
+        Trait: ?Sized + Send + Sync + 'static,
+        Concrete: Injectable,
+    {
+        self.container.bind::<Trait, Concrete>(coerce);
+    }
+
+    /// Alias for `bind`. See `Container::bind_interface`.
     #[inline]
-    pub fn try_resolve<T: Injectable>(&self) -> Option<Arc<T>> {
-        self.try_get::<T>()
+    pub fn bind_interface<Trait, Concrete>(&self, coerce: impl Fn(Arc<Concrete>) -> Arc<Trait> + Send + Sync + 'static)
+    where
+        Trait: ?Sized + Send + Sync + 'static,
+        Concrete: Injectable,
+    {
+        self.container.bind::<Trait, Concrete>(coerce);
+    }
+
+    /// Resolve a trait-object interface bound in this scope or a parent. See
+    /// `Container::get_dyn`.
+    #[inline]
+    pub fn get_dyn<Trait: ?Sized + Send + Sync + 'static>(&self) -> Result<Arc<Trait>> {
+        self.container.get_dyn::<Trait>()
+    }
+
+    /// Try to resolve a trait-object interface, returning `None` if unbound.
+    /// See `Container::try_get_dyn`.
+    #[inline]
+    pub fn try_get_dyn<Trait: ?Sized + Send + Sync + 'static>(&self) -> Option<Arc<Trait>> {
+        self.container.try_get_dyn::<Trait>()
+    }
+
+    /// Check if a trait-object interface has been bound in this scope or a
+    /// parent. See `Container::contains_dyn`.
+    #[inline]
+    pub fn contains_dyn<Trait: ?Sized + Send + Sync + 'static>(&self) -> bool {
+        self.container.contains_dyn::<Trait>()
     }
 
     /// Check if a service exists in this scope or parent scopes.
@@ -273,8 +401,10 @@ impl std::fmt::Debug for ScopedContainer {
 /// // Each scope gets its own services
 /// ```
 pub struct ScopeBuilder {
+    // `Arc` rather than `Box` so `extend` can share factories between builders
+    // instead of needing them to be re-constructed or `Clone`-able themselves.
     #[allow(clippy::type_complexity)]
-    factories: Vec<Box<dyn Fn(&Container) + Send + Sync>>,
+    factories: Vec<Arc<dyn Fn(&Container) + Send + Sync>>,
 }
 
 impl ScopeBuilder {
@@ -292,7 +422,7 @@ impl ScopeBuilder {
         T: Injectable + Clone,
         F: Fn() -> T + Send + Sync + 'static,
     {
-        self.factories.push(Box::new(move |container| {
+        self.factories.push(Arc::new(move |container| {
             container.singleton(factory());
         }));
         self
@@ -304,7 +434,7 @@ impl ScopeBuilder {
         T: Injectable,
         F: Fn() -> T + Send + Sync + Clone + 'static,
     {
-        self.factories.push(Box::new(move |container| {
+        self.factories.push(Arc::new(move |container| {
             let f = factory.clone();
             container.lazy(f);
         }));
@@ -317,13 +447,33 @@ impl ScopeBuilder {
         T: Injectable,
         F: Fn() -> T + Send + Sync + Clone + 'static,
     {
-        self.factories.push(Box::new(move |container| {
+        self.factories.push(Arc::new(move |container| {
             let f = factory.clone();
             container.transient(f);
         }));
         self
     }
 
+    /// Add a scoped factory - one instance per built scope, cached for that
+    /// scope's lifetime, the same "scoped" lifetime `Container::scoped`
+    /// registers directly. Since `build` registers each factory freshly on
+    /// every `ScopedContainer` it creates, this behaves like `with_lazy`
+    /// here (the scope it's registered on *is* the resolving scope) - use it
+    /// over `with_lazy` anyway when the service is also meant to be
+    /// registerable via plain `Container::scoped` elsewhere, so both call
+    /// sites agree on the lifetime a reader sees at a glance.
+    pub fn with_scoped<T, F>(mut self, factory: F) -> Self
+    where
+        T: Injectable,
+        F: Fn() -> T + Send + Sync + Clone + 'static,
+    {
+        self.factories.push(Arc::new(move |container| {
+            let f = factory.clone();
+            container.scoped(f);
+        }));
+        self
+    }
+
     /// Build a scoped container with all registered services.
     pub fn build(&self, parent: &Container) -> ScopedContainer {
         let scoped = ScopedContainer::from_parent(parent);
@@ -332,6 +482,32 @@ impl ScopeBuilder {
         }
         scoped
     }
+
+    /// Build a grandchild scope from an existing `ScopedContainer`, using
+    /// `ScopedContainer::from_scope` under the hood.
+    ///
+    /// Lets a pre-configured builder (e.g. standard per-request
+    /// registrations) spawn its scope layered on top of another `ScopedContainer`
+    /// (e.g. a per-session one), without re-registering services by hand at
+    /// each level of the hierarchy.
+    pub fn build_from_scope(&self, parent: &ScopedContainer) -> ScopedContainer {
+        let scoped = ScopedContainer::from_scope(parent);
+        for factory in &self.factories {
+            factory(&scoped.container);
+        }
+        scoped
+    }
+
+    /// Merge another builder's factories onto this one, in `other`'s
+    /// registration order, run after this builder's own factories by
+    /// `build`/`build_from_scope`.
+    ///
+    /// Lets standard per-request registrations be layered onto per-session
+    /// ones: `session_builder.extend(&request_builder)`.
+    pub fn extend(mut self, other: &ScopeBuilder) -> Self {
+        self.factories.extend(other.factories.iter().cloned());
+        self
+    }
 }
 
 impl Default for ScopeBuilder {
@@ -394,10 +570,162 @@ mod tests {
         assert_eq!(req.id, "built");
     }
 
+    #[test]
+    fn test_scope_builder_build_from_scope_layers_on_parent_scope() {
+        let root = Container::new();
+        root.singleton(GlobalService);
+
+        let session_builder = ScopeBuilder::new().with_singleton(|| RequestService { id: "session".into() });
+        let session = session_builder.build(&root);
+
+        #[derive(Clone)]
+        struct ConnectionId(u32);
+
+        let request_builder = ScopeBuilder::new().with_singleton(|| ConnectionId(7));
+        let request = request_builder.build_from_scope(&session);
+
+        assert!(request.contains::<GlobalService>());
+        assert!(request.contains::<RequestService>());
+        assert!(request.contains::<ConnectionId>());
+        assert_eq!(request.get::<ConnectionId>().unwrap().0, 7);
+
+        // The session scope itself never saw the request-only registration.
+        assert!(!session.contains::<ConnectionId>());
+    }
+
+    #[test]
+    fn test_scope_builder_extend_merges_factories() {
+        #[derive(Clone)]
+        struct ConnectionId(u32);
+
+        let session_builder = ScopeBuilder::new().with_singleton(|| RequestService { id: "session".into() });
+        let request_builder = ScopeBuilder::new().with_singleton(|| ConnectionId(7));
+
+        let combined = session_builder.extend(&request_builder);
+
+        let root = Container::new();
+        let scoped = combined.build(&root);
+
+        assert!(scoped.contains::<RequestService>());
+        assert!(scoped.contains::<ConnectionId>());
+    }
+
+    #[test]
+    fn test_scope_builder_with_scoped_caches_per_built_scope() {
+        static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+        #[derive(Clone)]
+        struct RequestId(u64);
+
+        let root = Container::new();
+        let builder = ScopeBuilder::new()
+            .with_scoped(|| RequestId(COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst)));
+
+        let scope1 = builder.build(&root);
+        let a = scope1.get::<RequestId>().unwrap();
+        let b = scope1.get::<RequestId>().unwrap();
+        assert_eq!(a.0, b.0); // Same instance within one scope
+
+        let scope2 = builder.build(&root);
+        let c = scope2.get::<RequestId>().unwrap();
+        assert_ne!(a.0, c.0); // A sibling scope gets a fresh instance
+    }
+
+    #[test]
+    fn test_scoped_container_dispose_runs_on_drop() {
+        let disposed = Arc::new(std::sync::Mutex::new(false));
+
+        let root = Container::new();
+        {
+            let scoped = ScopedContainer::from_parent(&root);
+            let disposed = Arc::clone(&disposed);
+            scoped.register_with_dispose(RequestService { id: "req-1".into() }, move |_| {
+                *disposed.lock().unwrap() = true;
+            });
+        } // scoped container drops here
+
+        assert!(*disposed.lock().unwrap());
+    }
+
+    #[test]
+    fn test_scoped_container_on_dispose_runs_without_registered_instance() {
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let root = Container::new();
+        {
+            let scoped = ScopedContainer::from_parent(&root);
+            for i in 0..3 {
+                let order = Arc::clone(&order);
+                scoped.on_dispose(move || {
+                    order.lock().unwrap().push(i);
+                });
+            }
+        } // scoped container drops here - hooks run LIFO
+
+        assert_eq!(*order.lock().unwrap(), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_scoped_container_resolver_reports_parent_dropped_after_drop() {
+        let root = Container::new();
+        let resolver = {
+            let scoped = ScopedContainer::from_parent(&root);
+            scoped.singleton(RequestService { id: "req-1".into() });
+            scoped.resolver::<RequestService>()
+        }; // scoped container drops here
+
+        assert!(resolver.get().is_err());
+        assert!(!resolver.is_scope_alive());
+    }
+
+    #[test]
+    fn test_scoped_container_pooled_checkout_and_return() {
+        struct Connection;
+
+        let root = Container::new();
+        let scoped = ScopedContainer::from_parent(&root);
+        scoped.pooled(|| Connection, 1);
+
+        let first = scoped.get_pooled::<Connection>().unwrap();
+        drop(first);
+
+        let second = scoped.get_pooled::<Connection>().unwrap();
+        let _ = second;
+    }
+
     #[test]
     fn test_scope_display() {
         let scope = Scope::new();
         let display = format!("{}", scope);
         assert!(display.starts_with("scope-"));
     }
+
+    #[test]
+    fn test_scoped_container_bind_and_get_dyn() {
+        trait Greeter: Send + Sync {
+            fn greet(&self) -> String;
+        }
+
+        #[derive(Clone)]
+        struct EnglishGreeter;
+
+        impl Greeter for EnglishGreeter {
+            fn greet(&self) -> String {
+                "hello".into()
+            }
+        }
+
+        let root = Container::new();
+        let scoped = ScopedContainer::from_parent(&root);
+        scoped.singleton(EnglishGreeter);
+        scoped.bind::<dyn Greeter, EnglishGreeter>(|c| c as Arc<dyn Greeter>);
+
+        assert!(scoped.contains_dyn::<dyn Greeter>());
+        let greeter = scoped.get_dyn::<dyn Greeter>().unwrap();
+        assert_eq!(greeter.greet(), "hello");
+
+        // Root never saw the scope-local binding.
+        assert!(!root.contains_dyn::<dyn Greeter>());
+        assert!(root.try_get_dyn::<dyn Greeter>().is_none());
+    }
 }
@@ -5,11 +5,17 @@
 
 #![allow(dead_code)]
 
-use crate::factory::AnyFactory;
+use crate::factory::{AnyFactory, AutowiredFactory, ReloadableFactory};
+#[cfg(feature = "async")]
+use crate::factory::AsyncFactory;
 use ahash::RandomState;
 use dashmap::DashMap;
 use std::any::{Any, TypeId};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
 
 #[cfg(feature = "perfect-hash")]
 use std::hash::{Hash, Hasher};
@@ -34,6 +40,20 @@ use std::hash::{Hash, Hasher};
 pub(crate) unsafe fn downcast_arc_unchecked<T: Send + Sync + 'static>(
     arc: Arc<dyn Any + Send + Sync>,
 ) -> Arc<T> {
+    // Debug-only frame-of-reference check: the `TypeId`-keyed map lookup and
+    // the slab/handle addressing schemes all rely on the invariant this
+    // function's contract states, but neither is proof against a `Arc`
+    // leaking across a scope boundary it was never registered in (e.g. a
+    // `ServiceKey<T>`/`ServiceHandle<T>` misused against the wrong
+    // `Container`). `Any::is` still works on the trait object here, so this
+    // compiles away entirely in release builds and costs nothing there.
+    debug_assert!(
+        (*arc).is::<T>(),
+        "downcast_arc_unchecked: type mismatch - expected {}, stored value is not that type \
+         (a handle or TypeId lookup was likely used against the wrong container/scope)",
+        std::any::type_name::<T>(),
+    );
+
     // SAFETY: The caller guarantees that the Arc contains a value of type T.
     // We convert Arc<dyn Any> -> raw pointer -> Arc<T>
     let ptr = Arc::into_raw(arc);
@@ -41,15 +61,737 @@ pub(crate) unsafe fn downcast_arc_unchecked<T: Send + Sync + 'static>(
     unsafe { Arc::from_raw(ptr as *const T) }
 }
 
+/// A resolver for a trait-object interface binding.
+///
+/// Captures the concrete type's lookup (honoring its registered `Lifetime`)
+/// and the upcast from `Arc<Concrete>` to `Arc<dyn Trait>`, type-erased as
+/// `Arc<dyn Any + Send + Sync>` wrapping the fat `Arc<dyn Trait>` pointer.
+pub(crate) type InterfaceResolver = Arc<dyn Fn() -> Option<Arc<dyn Any + Send + Sync>> + Send + Sync>;
+
+/// Number of shards the index-addressed slab (see [`ServiceKey`]) is split
+/// into. Matches the 8-shard default every `DashMap` field on
+/// `ServiceStorage` starts with.
+const SLAB_SHARDS: usize = 8;
+
+/// A stable, hash-free address for a factory registered via
+/// `Container::singleton`/`lazy`/`transient`, naming exactly the
+/// shard+slot it landed at in `ServiceStorage`'s index-addressed slab.
+///
+/// Resolving through `Container::get_by_key` is a bounds-checked array
+/// index plus an `Arc` clone - no `TypeId` hashing, no `DashMap` probe, and
+/// no thread-local hot-cache lookup. Cache the key returned at registration
+/// time (typically at startup) and reuse it on every hot-path resolve
+/// instead of `Container::get::<T>()`.
+///
+/// A key is only valid against the exact `ServiceStorage` it was issued
+/// by - it does not walk the parent chain the way `Container::get` does.
+pub struct ServiceKey<T> {
+    shard: u32,
+    slot: u32,
+    /// The issuing storage's `id()`, checked by `Container::get_by_key`
+    /// before trusting `address()` - see `ServiceStorage::id`.
+    storage_id: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ServiceKey<T> {
+    #[inline]
+    pub(crate) fn new(shard: u32, slot: u32, storage_id: u64) -> Self {
+        Self {
+            shard,
+            slot,
+            storage_id,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn address(&self) -> (u32, u32) {
+        (self.shard, self.slot)
+    }
+
+    /// The `ServiceStorage::id()` of the storage this key was issued by.
+    #[inline]
+    pub(crate) fn storage_id(&self) -> u64 {
+        self.storage_id
+    }
+}
+
+impl<T> Clone for ServiceKey<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ServiceKey<T> {}
+
+impl<T> std::fmt::Debug for ServiceKey<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceKey")
+            .field("shard", &self.shard)
+            .field("slot", &self.slot)
+            .field("storage_id", &self.storage_id)
+            .finish()
+    }
+}
+
+/// Per-key memoized instances for a `Container::register_keyed` registration.
+///
+/// One of these exists per `TypeId::of::<T>()` registered via
+/// `register_keyed` - the keyed analogue of a single `factories` entry.
+/// Keys are hashed rather than stored (in the style of moxie's `dyn_cache`
+/// keyed memoization), so this never needs to know the concrete `K` after
+/// construction - only `get_or_create` does, and only for long enough to
+/// compute a hash.
+pub(crate) struct KeyedRegistry {
+    /// Type-erased factory: takes the key (erased as `&dyn Any`, downcast
+    /// back to `&K` inside the closure captured at `register_keyed` time)
+    /// and produces the type-erased instance.
+    factory: Box<dyn Fn(&dyn Any) -> Arc<dyn Any + Send + Sync> + Send + Sync>,
+    /// Memoized instances, one per distinct key hash seen so far.
+    instances: DashMap<u64, Arc<dyn Any + Send + Sync>, RandomState>,
+    /// Hasher shared by every `get_or_create` call on this registration, so
+    /// the same key always hashes the same way across calls.
+    hasher: RandomState,
+}
+
+impl KeyedRegistry {
+    /// Create a new keyed registration from a factory closure.
+    pub(crate) fn new<K, T, F>(factory: F) -> Self
+    where
+        K: Hash + Eq + 'static,
+        T: Send + Sync + 'static,
+        F: Fn(&K) -> T + Send + Sync + 'static,
+    {
+        Self {
+            factory: Box::new(move |key| {
+                let key = key
+                    .downcast_ref::<K>()
+                    .expect("KeyedRegistry key type mismatch");
+                Arc::new(factory(key)) as Arc<dyn Any + Send + Sync>
+            }),
+            instances: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Return the memoized instance for `key`, creating (and caching) it on
+    /// first sight. Concurrent first-sight callers for the same key block on
+    /// each other via the `DashMap` shard lock inside `entry`, so the factory
+    /// still runs at most once per key - not just once per caller.
+    pub(crate) fn get_or_create<K: Hash + Eq + 'static>(&self, key: &K) -> Arc<dyn Any + Send + Sync> {
+        let hash = self.hasher.hash_one(key);
+
+        if let Some(existing) = self.instances.get(&hash) {
+            return Arc::clone(&existing);
+        }
+
+        let key_any: &dyn Any = key;
+        Arc::clone(
+            &self
+                .instances
+                .entry(hash)
+                .or_insert_with(|| (self.factory)(key_any)),
+        )
+    }
+}
+
+// =============================================================================
+// Pluggable Resolution Cache
+// =============================================================================
+
+/// A per-scope cache of resolved instances, consulted by `ServiceStorage::resolve`
+/// before the `factories` `DashMap`.
+///
+/// Implementations are not required to be thread-safe internally - a
+/// `ServiceStorage`'s cache is guarded by its own lock (see
+/// `ServiceStorage::cache`), so `&mut self` access is already serialized.
+pub trait CacheStorage: Send + Sync {
+    /// Look up a cached instance for `type_id`, if one is present.
+    fn get(&mut self, type_id: &TypeId) -> Option<&Arc<dyn Any + Send + Sync>>;
+
+    /// Cache `instance` under `type_id`, replacing any prior entry.
+    fn insert(&mut self, type_id: TypeId, instance: Arc<dyn Any + Send + Sync>);
+
+    /// Evict the entry for `type_id`, if present.
+    fn remove(&mut self, type_id: &TypeId);
+
+    /// Evict every cached entry.
+    fn clear(&mut self);
+}
+
+/// Instantiates one [`CacheStorage`] per scope - mirrors how each
+/// `ServiceStorage` gets its own `factories`/`scoped` maps rather than
+/// sharing them with its parent.
+pub trait CacheFactory: Send + Sync {
+    /// Create a fresh, empty cache for a newly-constructed `ServiceStorage`.
+    fn create(&self) -> Box<dyn CacheStorage>;
+}
+
+/// Unbounded [`CacheStorage`] backed by a plain `HashMap` - never evicts.
+#[derive(Default)]
+pub struct HashMapCache {
+    entries: std::collections::HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl HashMapCache {
+    /// Create an empty, unbounded cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStorage for HashMapCache {
+    fn get(&mut self, type_id: &TypeId) -> Option<&Arc<dyn Any + Send + Sync>> {
+        self.entries.get(type_id)
+    }
+
+    fn insert(&mut self, type_id: TypeId, instance: Arc<dyn Any + Send + Sync>) {
+        self.entries.insert(type_id, instance);
+    }
+
+    fn remove(&mut self, type_id: &TypeId) {
+        self.entries.remove(type_id);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Factory for [`HashMapCache`].
+#[derive(Default)]
+pub struct HashMapCacheFactory;
+
+impl CacheFactory for HashMapCacheFactory {
+    fn create(&self) -> Box<dyn CacheStorage> {
+        Box::new(HashMapCache::new())
+    }
+}
+
+/// Fixed-capacity [`CacheStorage`] that evicts the least-recently-used
+/// resolved instance once full.
+///
+/// Maintains an intrusive usage-order list (`order`, most-recently-used at
+/// the back) alongside the map: every `get`/`insert` moves the touched key
+/// to the back, and `insert` pops the front (the least-recently-used key)
+/// once `entries.len()` would exceed `capacity`.
+pub struct LruCache {
+    capacity: usize,
+    entries: std::collections::HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+    order: std::collections::VecDeque<TypeId>,
+}
+
+impl LruCache {
+    /// Create a cache that holds at most `capacity` resolved instances.
+    ///
+    /// `capacity == 0` means every `insert` is immediately evicted.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: std::collections::HashMap::new(),
+            order: std::collections::VecDeque::new(),
+        }
+    }
+
+    /// Move `type_id` to the back (most-recently-used end) of `order`.
+    fn touch(&mut self, type_id: &TypeId) {
+        if let Some(pos) = self.order.iter().position(|id| id == type_id) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(*type_id);
+    }
+}
+
+impl CacheStorage for LruCache {
+    fn get(&mut self, type_id: &TypeId) -> Option<&Arc<dyn Any + Send + Sync>> {
+        if self.entries.contains_key(type_id) {
+            self.touch(type_id);
+        }
+        self.entries.get(type_id)
+    }
+
+    fn insert(&mut self, type_id: TypeId, instance: Arc<dyn Any + Send + Sync>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&type_id) && self.entries.len() >= self.capacity {
+            if let Some(lru) = self.order.pop_front() {
+                self.entries.remove(&lru);
+            }
+        }
+        self.entries.insert(type_id, instance);
+        self.touch(&type_id);
+    }
+
+    fn remove(&mut self, type_id: &TypeId) {
+        self.entries.remove(type_id);
+        if let Some(pos) = self.order.iter().position(|id| id == type_id) {
+            self.order.remove(pos);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Factory for [`LruCache`] with a fixed `capacity`.
+pub struct LruCacheFactory {
+    capacity: usize,
+}
+
+impl LruCacheFactory {
+    /// Create a factory that produces `LruCache`s bounded to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity }
+    }
+}
+
+impl CacheFactory for LruCacheFactory {
+    fn create(&self) -> Box<dyn CacheStorage> {
+        Box::new(LruCache::new(self.capacity))
+    }
+}
+
+// =============================================================================
+// Opt-in Thread-Local Fast-Path Cache
+// =============================================================================
+
+/// Global free-list of dense thread ids, reused as threads exit so the id
+/// space stays tight instead of growing with every thread ever spawned -
+/// the same trick a compact thread-local allocator uses to index a `Vec`
+/// by thread instead of hashing `ThreadId`.
+static FREE_THREAD_IDS: std::sync::Mutex<Vec<usize>> = std::sync::Mutex::new(Vec::new());
+
+/// Next never-before-issued dense thread id, handed out once the free list
+/// is empty.
+static NEXT_THREAD_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Releases its thread id back to `FREE_THREAD_IDS` when the owning thread
+/// exits, so a long-running process with many short-lived threads doesn't
+/// leak ids (and the `Vec<ThreadCacheShard>` every `FastPathCache` keeps
+/// doesn't grow unbounded either).
+struct ThreadIdSlot(usize);
+
+impl ThreadIdSlot {
+    fn acquire() -> Self {
+        let id = FREE_THREAD_IDS
+            .lock()
+            .unwrap()
+            .pop()
+            .unwrap_or_else(|| NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed));
+        Self(id)
+    }
+}
+
+impl Drop for ThreadIdSlot {
+    fn drop(&mut self) {
+        FREE_THREAD_IDS.lock().unwrap().push(self.0);
+    }
+}
+
+thread_local! {
+    static THREAD_ID_SLOT: ThreadIdSlot = ThreadIdSlot::acquire();
+}
+
+/// This thread's dense id, stable for the thread's lifetime and reused by a
+/// later thread once this one exits.
+#[inline]
+fn current_thread_id() -> usize {
+    THREAD_ID_SLOT.with(|slot| slot.0)
+}
+
+/// One thread's cached entries within a [`FastPathCache`]: the resolved
+/// instance plus the storage epoch it was resolved at, so a later
+/// `insert`/`remove`/`clear` elsewhere is noticed without cross-thread
+/// signaling - see `FastPathCache::get`.
+type ThreadCacheShard = std::sync::Mutex<HashMap<TypeId, (u64, Arc<dyn Any + Send + Sync>)>>;
+
+/// Opt-in per-thread resolution cache for a single `ServiceStorage`.
+///
+/// Indexed by the dense id from `current_thread_id()` rather than hashing a
+/// `ThreadId`, so a hit is a bounds-checked `Vec` index plus a small
+/// `HashMap` lookup guarded by that thread's own shard lock - no contention
+/// with other threads resolving the same or different types.
+#[derive(Default)]
+struct FastPathCache {
+    shards: RwLock<Vec<ThreadCacheShard>>,
+}
+
+impl FastPathCache {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` against this thread's shard, growing `shards` first if this
+    /// is the first time `thread_id` has been seen by this cache.
+    fn with_shard<R>(
+        &self,
+        thread_id: usize,
+        f: impl FnOnce(&mut HashMap<TypeId, (u64, Arc<dyn Any + Send + Sync>)>) -> R,
+    ) -> R {
+        {
+            let shards = self.shards.read().unwrap();
+            if let Some(shard) = shards.get(thread_id) {
+                return f(&mut shard.lock().unwrap());
+            }
+        }
+
+        let mut shards = self.shards.write().unwrap();
+        while shards.len() <= thread_id {
+            shards.push(std::sync::Mutex::new(HashMap::new()));
+        }
+        f(&mut shards[thread_id].lock().unwrap())
+    }
+
+    /// Return the cached instance for `type_id` on this thread, provided it
+    /// was cached at the same `epoch` the storage is still at.
+    fn get(&self, type_id: &TypeId, epoch: u64) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.with_shard(current_thread_id(), |shard| {
+            shard
+                .get(type_id)
+                .filter(|(cached_epoch, _)| *cached_epoch == epoch)
+                .map(|(_, instance)| Arc::clone(instance))
+        })
+    }
+
+    /// Cache `instance` under `type_id` for this thread, tagged with `epoch`.
+    fn insert(&self, type_id: TypeId, epoch: u64, instance: Arc<dyn Any + Send + Sync>) {
+        self.with_shard(current_thread_id(), |shard| {
+            shard.insert(type_id, (epoch, instance));
+        });
+    }
+}
+
+// =============================================================================
+// Sharded-Slab Service Handles
+// =============================================================================
+
+/// Number of shards `register_handle` partitions slots across - one per
+/// bucket of `current_thread_id() % HANDLE_SHARD_COUNT`, so registrations
+/// from different threads rarely contend on the same shard's lock.
+const HANDLE_SHARD_COUNT: usize = 16;
+
+/// Slots per lazily-allocated page in a `HandleShard`. Kept small so a shard
+/// that only ever sees a handful of registrations doesn't pay for a large
+/// upfront allocation.
+const HANDLE_PAGE_SLOTS: usize = 64;
+
+/// Bit widths of the four fields packed into a `ServiceHandle`'s `usize`.
+/// `shard` and `page`/`slot` only need to address `HANDLE_SHARD_COUNT` and
+/// `HANDLE_PAGE_SLOTS`-sized pages respectively, so half of the 64 bits go to
+/// `generation` - the field that actually needs headroom, since it's bumped
+/// on every slot reuse for the life of the shard.
+const HANDLE_SHARD_BITS: u32 = 8;
+const HANDLE_PAGE_BITS: u32 = 16;
+const HANDLE_SLOT_BITS: u32 = 8;
+const HANDLE_GENERATION_BITS: u32 = 32;
+
+const HANDLE_PAGE_SHIFT: u32 = HANDLE_SLOT_BITS;
+const HANDLE_SHARD_SHIFT: u32 = HANDLE_PAGE_SHIFT + HANDLE_PAGE_BITS;
+const HANDLE_GENERATION_SHIFT: u32 = HANDLE_SHARD_SHIFT + HANDLE_SHARD_BITS;
+
+const _: () = assert!(
+    HANDLE_GENERATION_SHIFT + HANDLE_GENERATION_BITS <= usize::BITS
+);
+
+/// A stable, `usize`-packed address for a factory registered via
+/// `ServiceStorage::register_handle`, naming the shard, page, and slot it
+/// landed in plus the generation the slot was claimed at.
+///
+/// Unlike `ServiceKey` (which addresses the append-only `slab` and can never
+/// be invalidated), a `ServiceHandle`'s slot can be freed and reused by a
+/// later registration - `resolve_by_handle` rejects a stale handle by
+/// comparing its packed generation against the slot's current one, so a
+/// handle outliving its registration's `remove` fails closed instead of
+/// silently resolving whatever was claimed afterward.
+pub struct ServiceHandle<T> {
+    packed: usize,
+    /// The issuing storage's `id()`, checked by `Container::get_by_handle`
+    /// before trusting `packed` - see `ServiceStorage::id`.
+    storage_id: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ServiceHandle<T> {
+    #[inline]
+    fn new(shard: usize, page: usize, slot: usize, generation: u32, storage_id: u64) -> Self {
+        let packed = (generation as usize) << HANDLE_GENERATION_SHIFT
+            | shard << HANDLE_SHARD_SHIFT
+            | page << HANDLE_PAGE_SHIFT
+            | slot;
+        Self {
+            packed,
+            storage_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Rebuild a handle from a previously packed `usize` - used internally
+    /// to recover a type-erased handle from `ServiceStorage::handles` (the
+    /// side map only stores the packed address, not `T`). The rebuilt
+    /// handle's `storage_id` is irrelevant here since it's only ever used to
+    /// free the slot on its own storage, never passed back to a caller.
+    #[inline]
+    fn from_packed(packed: usize) -> Self {
+        Self {
+            packed,
+            storage_id: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The `ServiceStorage::id()` of the storage this handle was issued by.
+    #[inline]
+    pub(crate) fn storage_id(&self) -> u64 {
+        self.storage_id
+    }
+
+    #[inline]
+    fn unpack(&self) -> (usize, usize, usize, u32) {
+        let shard_mask = (1usize << HANDLE_SHARD_BITS) - 1;
+        let page_mask = (1usize << HANDLE_PAGE_BITS) - 1;
+        let slot_mask = (1usize << HANDLE_SLOT_BITS) - 1;
+        let shard = (self.packed >> HANDLE_SHARD_SHIFT) & shard_mask;
+        let page = (self.packed >> HANDLE_PAGE_SHIFT) & page_mask;
+        let slot = self.packed & slot_mask;
+        let generation = (self.packed >> HANDLE_GENERATION_SHIFT) as u32;
+        (shard, page, slot, generation)
+    }
+}
+
+impl<T> Clone for ServiceHandle<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ServiceHandle<T> {}
+
+impl<T> std::fmt::Debug for ServiceHandle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (shard, page, slot, generation) = self.unpack();
+        f.debug_struct("ServiceHandle")
+            .field("shard", &shard)
+            .field("page", &page)
+            .field("slot", &slot)
+            .field("generation", &generation)
+            .finish()
+    }
+}
+
+/// One slot in a `HandlePage` - either holding a live factory at the
+/// generation it was claimed at, or vacant and awaiting reuse.
+struct HandleSlot {
+    factory: Option<Arc<AnyFactory>>,
+    generation: u32,
+}
+
+impl HandleSlot {
+    fn vacant() -> Self {
+        Self {
+            factory: None,
+            generation: 0,
+        }
+    }
+}
+
+/// A fixed-size, lazily-allocated page of `HANDLE_PAGE_SLOTS` slots.
+type HandlePage = Vec<HandleSlot>;
+
+/// One shard of the handle slab - owns a growable list of lazily-allocated
+/// pages plus a free list of `(page, slot)` addresses freed by `free`, so a
+/// subsequent `claim` reuses the address (bumping its generation) instead of
+/// growing the shard unboundedly under churn.
+#[derive(Default)]
+struct HandleShard {
+    pages: RwLock<Vec<HandlePage>>,
+    free: std::sync::Mutex<Vec<(usize, usize)>>,
+}
+
+impl HandleShard {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Claim a slot for `factory`, reusing a freed `(page, slot)` address if
+    /// one is available, otherwise allocating (growing a page, or adding a
+    /// new one) - returns the `(page, slot, generation)` it landed at.
+    fn claim(&self, factory: Arc<AnyFactory>) -> (usize, usize, u32) {
+        if let Some((page, slot)) = self.free.lock().unwrap().pop() {
+            let mut pages = self.pages.write().unwrap();
+            let slot_ref = &mut pages[page][slot];
+            slot_ref.factory = Some(factory);
+            return (page, slot, slot_ref.generation);
+        }
+
+        let mut pages = self.pages.write().unwrap();
+        if pages.is_empty() || pages.last().unwrap().len() == HANDLE_PAGE_SLOTS {
+            pages.push(Vec::with_capacity(HANDLE_PAGE_SLOTS));
+        }
+        let page = pages.len() - 1;
+        let slot_vec = pages.last_mut().unwrap();
+        let slot = slot_vec.len();
+        slot_vec.push(HandleSlot {
+            factory: Some(factory),
+            generation: 0,
+        });
+        (page, slot, 0)
+    }
+
+    /// Resolve the factory at `(page, slot)` if it's still live and its
+    /// generation matches - a stale handle (its slot freed and reclaimed
+    /// since) returns `None` rather than resolving the wrong service.
+    fn get(&self, page: usize, slot: usize, generation: u32) -> Option<Arc<dyn Any + Send + Sync>> {
+        let pages = self.pages.read().unwrap();
+        let slot_ref = pages.get(page)?.get(slot)?;
+        if slot_ref.generation != generation {
+            return None;
+        }
+        slot_ref.factory.as_ref().map(|f| f.resolve())
+    }
+
+    /// Free the slot at `(page, slot)`, bumping its generation so any handle
+    /// still pointing at it is rejected by a subsequent `get`, and returning
+    /// the address to the free list for reuse by `claim`.
+    fn free(&self, page: usize, slot: usize) {
+        let mut pages = self.pages.write().unwrap();
+        if let Some(slot_ref) = pages.get_mut(page).and_then(|p| p.get_mut(slot)) {
+            slot_ref.factory = None;
+            slot_ref.generation = slot_ref.generation.wrapping_add(1);
+            drop(pages);
+            self.free.lock().unwrap().push((page, slot));
+        }
+    }
+}
+
+/// Build `HANDLE_SHARD_COUNT` empty shards for a new `ServiceStorage`.
+#[inline]
+fn new_handle_shards() -> Vec<HandleShard> {
+    (0..HANDLE_SHARD_COUNT).map(|_| HandleShard::new()).collect()
+}
+
+/// Source of unique "frame of reference" ids handed out to every
+/// `ServiceStorage`/`FrozenStorage` at creation - see `ServiceStorage::id`.
+static NEXT_STORAGE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Mint a new, process-wide-unique storage id.
+#[inline]
+fn next_storage_id() -> u64 {
+    NEXT_STORAGE_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 /// Thread-safe storage for service factories
 ///
 /// Uses `DashMap` with `ahash` for maximum concurrent performance.
 /// Supports hierarchical parent chain for deep scope resolution.
 pub struct ServiceStorage {
     /// Map from TypeId to factory
-    factories: DashMap<TypeId, AnyFactory, RandomState>,
+    factories: DashMap<TypeId, Arc<AnyFactory>, RandomState>,
+    /// Index-addressed slab backing `ServiceKey`/`insert_indexed`/
+    /// `get_by_slab` - each shard is its own `RwLock<Vec<_>>`, append-only,
+    /// so a `(shard, slot)` address stays valid for the storage's lifetime.
+    ///
+    /// Shares the same `Arc<AnyFactory>` as `factories` for any type
+    /// registered via `insert_indexed`, so the two addressing schemes never
+    /// see diverging state (e.g. two independent `OnceCell`s for one `Lazy`
+    /// registration).
+    slab: Vec<RwLock<Vec<Arc<AnyFactory>>>>,
+    /// Round-robins `insert_indexed` across `slab`'s shards to spread
+    /// concurrent registration-time writes.
+    next_slab_shard: AtomicUsize,
+    /// Map from `TypeId::of::<dyn Trait>()` to its interface resolver.
+    ///
+    /// Kept separate from `factories` because the resolved value is an
+    /// `Arc<dyn Trait>` (itself boxed as `Arc<dyn Any>`), not a concrete `T`.
+    interfaces: DashMap<TypeId, InterfaceResolver, RandomState>,
+    /// Map from TypeId to every factory registered via `append`/`append_lazy`/
+    /// `append_transient` for that type.
+    ///
+    /// Kept separate from `factories` since `factories` is a single-slot map
+    /// (each registration replaces the prior one); this supports the
+    /// resolve-all pattern where several implementations share one key.
+    /// Storing `AnyFactory` rather than pre-resolved instances means each
+    /// entry honors its own singleton/lazy/transient lifetime on resolve.
+    multi: DashMap<TypeId, Vec<AnyFactory>, RandomState>,
+    /// Map from `TypeId::of::<dyn Trait>()` to every `Arc<dyn Trait>` (itself
+    /// boxed as `Arc<dyn Any>`) registered via `Container::register_many` for
+    /// that trait.
+    ///
+    /// The trait-object analogue of `multi`: instances are already coerced
+    /// and eager (unlike `multi`'s `AnyFactory` entries), since
+    /// `register_many` takes an `Arc<dyn Trait>` directly rather than a
+    /// factory for a concrete `T`.
+    multi_interfaces: DashMap<TypeId, Vec<Arc<dyn Any + Send + Sync>>, RandomState>,
+    /// Map from (`TypeId::of::<dyn Trait>()`, name) to a single named
+    /// `Arc<dyn Trait>` registered via `Container::register_named`.
+    ///
+    /// A later `register_named` call with the same trait and name replaces
+    /// the prior entry, mirroring `interfaces`' single-slot-per-key semantics
+    /// - `register_many`/`multi_interfaces` is the one that accumulates.
+    named_interfaces: DashMap<(TypeId, &'static str), Arc<dyn Any + Send + Sync>, RandomState>,
+    /// Map from `TypeId::of::<T>()` to its `Container::register_keyed`
+    /// registration - one factory per type, memoizing an instance per
+    /// distinct key argument. See `KeyedRegistry`.
+    keyed: DashMap<TypeId, KeyedRegistry, RandomState>,
+    /// Memoized instances for `Scoped` registrations, local to this scope only.
+    ///
+    /// Never inherited from or shared with a parent/child - a `Scoped`
+    /// factory may live on an ancestor, but each scope that resolves it gets
+    /// its own entry here, created on first access and dropped along with
+    /// this storage. See `resolve_scoped`.
+    scoped: DashMap<TypeId, Arc<dyn Any + Send + Sync>, RandomState>,
     /// Optional parent storage for hierarchical resolution
     parent: Option<Arc<ServiceStorage>>,
+    /// Dispose closures registered via `register_with_dispose`, in
+    /// registration order.
+    ///
+    /// Run in reverse (LIFO) order - mirroring construction order, like a
+    /// stack unwind - when this storage is dropped or `clear()`'d. Never
+    /// inherited: a scope only disposes services it owns, not its parent's.
+    disposers: std::sync::Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+    /// Pluggable resolution cache, consulted by `resolve` before `factories`.
+    ///
+    /// `None` unless constructed via `with_cache` - the common case pays no
+    /// locking cost at all. When present, only non-transient resolves are
+    /// cached (see `resolve`), since a transient service's whole point is a
+    /// fresh instance per call.
+    cache: Option<std::sync::Mutex<Box<dyn CacheStorage>>>,
+    /// Monotonically increasing registration epoch, bumped by every
+    /// `insert`/`remove`/`clear` - lets `fast_path` entries cached on other
+    /// threads be invalidated by a plain integer comparison instead of
+    /// cross-thread signaling. Always live (not `Option`-gated, unlike
+    /// `fast_path`/`cache`) since bumping an `AtomicU64` is cheap even when
+    /// no fast-path cache is installed.
+    epoch: AtomicU64,
+    /// Opt-in thread-local fast-path cache - `None` unless constructed via
+    /// `with_fast_path_cache`.
+    fast_path: Option<FastPathCache>,
+    /// Sharded, page-based slab backing `ServiceHandle`/`register_handle`/
+    /// `resolve_by_handle` - unlike `slab`, slots here are reclaimed and
+    /// reused (see `HandleShard::free`), so an address alone isn't enough to
+    /// tell a live registration from a stale one; the handle's packed
+    /// generation has to match the slot's current one too.
+    handle_shards: Vec<HandleShard>,
+    /// Map from `TypeId::of::<T>()` to the packed address `register_handle`
+    /// last claimed for it, so `remove` can free the slot without the caller
+    /// having to keep its `ServiceHandle` around just to tear it down.
+    handles: DashMap<TypeId, usize, RandomState>,
+    /// Unique "frame of reference" id minted at creation (see
+    /// `next_storage_id`) - stamped onto every `ServiceKey`/`ServiceHandle`
+    /// issued by this storage so a later lookup can reject one issued by a
+    /// different storage instead of misapplying it to this frame's slab.
+    id: u64,
+}
+
+/// Build an empty, `SLAB_SHARDS`-wide slab for a new `ServiceStorage`.
+#[inline]
+fn new_slab() -> Vec<RwLock<Vec<Arc<AnyFactory>>>> {
+    (0..SLAB_SHARDS).map(|_| RwLock::new(Vec::new())).collect()
 }
 
 impl ServiceStorage {
@@ -69,105 +811,735 @@ impl ServiceStorage {
                 RandomState::new(),
                 8, // 8 shards balances creation speed vs concurrency
             ),
+            interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            named_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            keyed: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            scoped: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            slab: new_slab(),
+            next_slab_shard: AtomicUsize::new(0),
             parent: None,
+            disposers: std::sync::Mutex::new(Vec::new()),
+            cache: None,
+            epoch: AtomicU64::new(0),
+            fast_path: None,
+            handle_shards: new_handle_shards(),
+            handles: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            id: next_storage_id(),
+        }
+    }
+
+    /// Create with pre-allocated capacity and optimized shards.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        // Scale shards based on expected capacity and concurrency needs
+        let shard_amount = if capacity <= 16 {
+            8
+        } else if capacity <= 64 {
+            16
+        } else {
+            32
+        };
+        Self {
+            factories: DashMap::with_capacity_and_hasher_and_shard_amount(
+                capacity,
+                RandomState::new(),
+                shard_amount,
+            ),
+            interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            named_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            keyed: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            scoped: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            slab: new_slab(),
+            next_slab_shard: AtomicUsize::new(0),
+            parent: None,
+            disposers: std::sync::Mutex::new(Vec::new()),
+            cache: None,
+            epoch: AtomicU64::new(0),
+            fast_path: None,
+            handle_shards: new_handle_shards(),
+            handles: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            id: next_storage_id(),
+        }
+    }
+
+    /// Create a child storage with a parent reference for deep hierarchy resolution.
+    #[inline]
+    pub fn with_parent(parent: Arc<ServiceStorage>) -> Self {
+        Self {
+            factories: DashMap::with_capacity_and_hasher_and_shard_amount(
+                0,
+                RandomState::new(),
+                8,
+            ),
+            interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            named_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            keyed: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            scoped: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            slab: new_slab(),
+            next_slab_shard: AtomicUsize::new(0),
+            parent: Some(parent),
+            disposers: std::sync::Mutex::new(Vec::new()),
+            cache: None,
+            epoch: AtomicU64::new(0),
+            fast_path: None,
+            handle_shards: new_handle_shards(),
+            handles: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            id: next_storage_id(),
+        }
+    }
+
+    /// Create new empty storage, like `new()`, with a per-scope resolution
+    /// cache minted by `cache_factory`.
+    ///
+    /// The cache is local to this storage only - like `scoped`, it is never
+    /// inherited by children; a scope wanting its own cache should call
+    /// `with_cache` again when it's constructed.
+    #[inline]
+    pub fn with_cache(cache_factory: &dyn CacheFactory) -> Self {
+        let mut storage = Self::new();
+        storage.cache = Some(std::sync::Mutex::new(cache_factory.create()));
+        storage
+    }
+
+    /// Create new empty storage, like `new()`, with the opt-in thread-local
+    /// fast-path cache enabled.
+    ///
+    /// Like `with_cache`, the cache is local to this storage only.
+    #[inline]
+    pub fn with_fast_path_cache() -> Self {
+        let mut storage = Self::new();
+        storage.fast_path = Some(FastPathCache::new());
+        storage
+    }
+
+    /// Insert a factory
+    #[inline]
+    pub fn insert(&self, type_id: TypeId, factory: AnyFactory) {
+        self.factories.insert(type_id, Arc::new(factory));
+        self.epoch.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Like `insert`, but also places the factory in the index-addressed
+    /// slab, returning the `(shard, slot)` address it landed at so a caller
+    /// can build a `ServiceKey<T>` from it.
+    ///
+    /// Used by `Container::singleton`/`lazy`/`transient` to back
+    /// `Container::get_by_key`. The `TypeId` map entry and the slab entry
+    /// share one `Arc<AnyFactory>`, so resolving through either path sees
+    /// the same state (e.g. one `OnceCell` for a `Lazy` registration, not two).
+    pub fn insert_indexed(&self, type_id: TypeId, factory: AnyFactory) -> (u32, u32) {
+        let factory = Arc::new(factory);
+        let address = self.insert_slab(Arc::clone(&factory));
+        self.factories.insert(type_id, factory);
+        self.epoch.fetch_add(1, Ordering::Relaxed);
+        address
+    }
+
+    /// Push `factory` into the slab, round-robining across shards to spread
+    /// concurrent registration-time writes, and return where it landed.
+    fn insert_slab(&self, factory: Arc<AnyFactory>) -> (u32, u32) {
+        let shard = self.next_slab_shard.fetch_add(1, Ordering::Relaxed) % self.slab.len();
+        let mut slot_vec = self.slab[shard].write().unwrap();
+        let slot = slot_vec.len() as u32;
+        slot_vec.push(factory);
+        (shard as u32, slot)
+    }
+
+    /// Resolve directly by slab address (see `ServiceKey`), bypassing the
+    /// `TypeId` map, `DashMap` hashing, and the thread-local hot cache
+    /// entirely - a bounds-checked array index plus an `Arc` clone.
+    #[inline]
+    pub fn get_by_slab(&self, shard: u32, slot: u32) -> Option<Arc<dyn Any + Send + Sync>> {
+        let slot_vec = self.slab.get(shard as usize)?.read().unwrap();
+        slot_vec.get(slot as usize).map(|f| f.resolve())
+    }
+
+    /// Like `insert`, but also claims a slot in the sharded, generation-
+    /// counted handle slab, returning the `ServiceHandle<T>` it landed at.
+    ///
+    /// The shard is chosen via `current_thread_id() % HANDLE_SHARD_COUNT`,
+    /// reusing the same dense thread-id assignment `with_fast_path_cache`
+    /// does, so registrations made from the same thread tend to land in the
+    /// same shard instead of round-robining like `insert_slab`. The
+    /// `TypeId` map entry and the handle slot share one `Arc<AnyFactory>`,
+    /// matching `insert_indexed`'s rule that every addressing scheme for one
+    /// registration sees the same state.
+    pub fn register_handle<T: Send + Sync + 'static>(
+        &self,
+        type_id: TypeId,
+        factory: AnyFactory,
+    ) -> ServiceHandle<T> {
+        let factory = Arc::new(factory);
+        let shard = current_thread_id() % self.handle_shards.len();
+        let (page, slot, generation) = self.handle_shards[shard].claim(Arc::clone(&factory));
+        let handle = ServiceHandle::<T>::new(shard, page, slot, generation, self.id);
+        self.handles.insert(type_id, handle.packed);
+        self.factories.insert(type_id, factory);
+        self.epoch.fetch_add(1, Ordering::Relaxed);
+        handle
+    }
+
+    /// Resolve directly by handle (see `ServiceHandle`), bypassing the
+    /// `TypeId` map and `DashMap` hashing entirely. Returns `None` if the
+    /// handle's slot has since been freed and reused - the one case this
+    /// addressing scheme has to check that `get_by_slab` doesn't, since
+    /// `slab` never reclaims a slot.
+    #[inline]
+    pub fn resolve_by_handle<T: Send + Sync + 'static>(
+        &self,
+        handle: ServiceHandle<T>,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        let (shard, page, slot, generation) = handle.unpack();
+        self.handle_shards.get(shard)?.get(page, slot, generation)
+    }
+
+    /// Check if type exists
+    #[inline]
+    pub fn contains(&self, type_id: &TypeId) -> bool {
+        self.factories.contains_key(type_id)
+    }
+
+    /// Resolve a service by TypeId.
+    ///
+    /// Returns `None` for a fallible, autowired, async-only, or pooled
+    /// registration - the first has an `Err` case this signature can't
+    /// report, the second needs a `&Container`, the third needs an `.await`,
+    /// and the fourth hands back an exclusive checkout instead of a shared
+    /// `Arc`, so all four are treated as a synchronous miss. Callers needing
+    /// a clear result for those cases should check `is_fallible_in_chain`/
+    /// `autowired_factory_in_chain`/`is_async_in_chain`/`is_pooled_in_chain`
+    /// before falling back to "not found" (see `Container::get`).
+    #[inline]
+    pub fn resolve(&self, type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        if let Some(fast_path) = &self.fast_path {
+            if let Some(hit) = fast_path.get(type_id, self.epoch.load(Ordering::Relaxed)) {
+                return Some(hit);
+            }
+        }
+
+        if let Some(cache) = &self.cache {
+            if let Some(hit) = cache.lock().unwrap().get(type_id) {
+                return Some(Arc::clone(hit));
+            }
+        }
+
+        let (instance, is_transient) = self.factories.get(type_id).and_then(|f| {
+            if f.is_fallible() || f.is_autowired() || f.is_async() || f.is_pooled() {
+                None
+            } else {
+                Some((f.resolve(), f.is_transient()))
+            }
+        })?;
+
+        if !is_transient {
+            if let Some(fast_path) = &self.fast_path {
+                fast_path.insert(*type_id, self.epoch.load(Ordering::Relaxed), Arc::clone(&instance));
+            }
+            if let Some(cache) = &self.cache {
+                cache.lock().unwrap().insert(*type_id, Arc::clone(&instance));
+            }
+        }
+
+        Some(instance)
+    }
+
+    /// Try to resolve and downcast to T
+    ///
+    /// Uses unchecked downcast since we know the type from the TypeId lookup.
+    #[inline]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.resolve(&TypeId::of::<T>()).map(|any| {
+            // SAFETY: We looked up by TypeId::of::<T>(), so the factory
+            // was registered with the same TypeId and stores type T.
+            unsafe { downcast_arc_unchecked(any) }
+        })
+    }
+
+    /// Resolve and return both the service and whether it's transient.
+    ///
+    /// This avoids a second DashMap lookup when checking if the service should be cached.
+    /// Returns `Some((service, is_transient))` if found, `None` if not found.
+    #[inline]
+    pub fn get_with_transient_flag<T: Send + Sync + 'static>(&self) -> Option<(Arc<T>, bool)> {
+        let type_id = TypeId::of::<T>();
+
+        // Scoped factories are memoized per-scope in `self.scoped`, not inside
+        // the factory itself (see `resolve_scoped`) - a plain `factory.resolve()`
+        // would create a fresh instance on every call instead of once per scope.
+        // A fallible, autowired, async-only, or pooled factory is treated the
+        // same as "not here" - there's no synchronous, infallible,
+        // container-free, shared value to hand back (see
+        // `Container::get_and_cache`, which checks `is_fallible_in_chain`/
+        // `autowired_factory_in_chain`/`is_async_in_chain`/`is_pooled_in_chain`
+        // before giving up).
+        let resolved = self.factories.get(&type_id).map(|factory| {
+            if factory.is_fallible() || factory.is_autowired() || factory.is_async() || factory.is_pooled() {
+                return None;
+            }
+            match factory.create_scoped() {
+                Some(_) => None,
+                None => Some((factory.resolve(), factory.is_transient())),
+            }
+        })?;
+
+        if let Some((service, is_transient)) = resolved {
+            // SAFETY: We looked up by TypeId::of::<T>(), so the factory stores type T.
+            let typed = unsafe { downcast_arc_unchecked(service) };
+            return Some((typed, is_transient));
+        }
+
+        let service = self.resolve_scoped(&type_id)?;
+        // SAFETY: We looked up by TypeId::of::<T>(), so the factory stores type T.
+        let typed = unsafe { downcast_arc_unchecked(service) };
+        Some((typed, false))
+    }
+
+    /// Resolve a `Scoped` registration, memoizing the result for this scope only.
+    ///
+    /// Returns an already-materialized instance if this exact scope resolved
+    /// `type_id` before. Otherwise finds the `Scoped` factory - checking this
+    /// scope first, then the full parent chain - creates a fresh instance,
+    /// caches it here, and returns it. A different scope (even a descendant
+    /// of this one, via its own `resolve_scoped` call) always gets its own.
+    pub fn resolve_scoped(&self, type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        if let Some(instance) = self.scoped.get(type_id) {
+            return Some(Arc::clone(&instance));
+        }
+
+        if let Some(instance) = self.factories.get(type_id).and_then(|f| f.create_scoped()) {
+            self.scoped.insert(*type_id, Arc::clone(&instance));
+            return Some(instance);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(instance) = storage.factories.get(type_id).and_then(|f| f.create_scoped()) {
+                self.scoped.insert(*type_id, Arc::clone(&instance));
+                return Some(instance);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Calling this (instead of `create_scoped` directly) makes the factory's
+    /// scoped-ness checkable without creating an instance - used by
+    /// `Container::resolve_from_parents` to decide whether a type found on an
+    /// ancestor must be routed through `resolve_scoped` instead of a plain
+    /// `resolve()`.
+    #[inline]
+    pub fn lifetime_is_scoped(&self, type_id: &TypeId) -> bool {
+        matches!(self.lifetime_in_chain(type_id), Some(crate::Lifetime::Scoped))
+    }
+
+    /// Resolve a service by walking the full parent chain.
+    ///
+    /// Returns the service from the nearest scope that has it registered.
+    #[inline]
+    pub fn resolve_from_chain(&self, type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        // Check current scope first
+        if let Some(service) = self.resolve(type_id) {
+            return Some(service);
+        }
+
+        // Walk parent chain
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(service) = storage.resolve(type_id) {
+                return Some(service);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Resolve several services in a single pass over the parent chain.
+    ///
+    /// Equivalent to calling `resolve_from_chain` once per id, but avoids
+    /// re-walking the whole chain for each one: builds a working set of
+    /// still-unresolved indices, checks the current scope once for all of
+    /// them, removes the hits, then descends one level and repeats until
+    /// the set empties or the chain ends. Results are positionally aligned
+    /// with `ids` - `results[i]` corresponds to `ids[i]`.
+    pub fn resolve_many(&self, ids: &[TypeId]) -> Vec<Option<Arc<dyn Any + Send + Sync>>> {
+        let mut results: Vec<Option<Arc<dyn Any + Send + Sync>>> = vec![None; ids.len()];
+        let mut pending: Vec<usize> = (0..ids.len()).collect();
+
+        let mut current = Some(self);
+        while let Some(storage) = current {
+            if pending.is_empty() {
+                break;
+            }
+
+            pending.retain(|&i| match storage.resolve(&ids[i]) {
+                Some(service) => {
+                    results[i] = Some(service);
+                    false
+                }
+                None => true,
+            });
+
+            current = storage.parent.as_deref();
+        }
+
+        results
+    }
+
+    /// Check if a service exists in this storage or any parent.
+    #[inline]
+    pub fn contains_in_chain(&self, type_id: &TypeId) -> bool {
+        // Check current scope first
+        if self.contains(type_id) {
+            return true;
+        }
+
+        // Walk parent chain
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if storage.contains(type_id) {
+                return true;
+            }
+            current = storage.parent.as_ref();
+        }
+
+        false
+    }
+
+    /// Resolve an autowired factory for `type_id`, walking the full parent
+    /// chain (nearest scope wins, matching `resolve_from_chain`'s
+    /// precedence). Returns the cloned `Arc<AutowiredFactory>` rather than an
+    /// already-resolved value, so the caller can hand it the `&Container` it
+    /// needs without holding any `DashMap` guard across its (potentially
+    /// recursive) construction.
+    #[inline]
+    pub fn autowired_factory_in_chain(&self, type_id: &TypeId) -> Option<Arc<AutowiredFactory>> {
+        if let Some(factory) = self.factories.get(type_id).and_then(|f| f.autowired_factory()) {
+            return Some(factory);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(factory) = storage.factories.get(type_id).and_then(|f| f.autowired_factory()) {
+                return Some(factory);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Resolve a reloadable factory for `type_id`, walking the full parent
+    /// chain (nearest scope wins, matching `resolve_from_chain`'s
+    /// precedence) - same shape as `autowired_factory_in_chain`, so
+    /// `Container::replace` can swap a `reloadable` registered on an
+    /// ancestor scope, not just the local one.
+    #[inline]
+    pub fn reloadable_factory_in_chain(&self, type_id: &TypeId) -> Option<Arc<ReloadableFactory>> {
+        if let Some(factory) = self.factories.get(type_id).and_then(|f| f.reloadable_factory()) {
+            return Some(factory);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(factory) = storage.factories.get(type_id).and_then(|f| f.reloadable_factory()) {
+                return Some(factory);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Check whether `type_id` is registered as a fallible factory
+    /// (`try_lazy`/`try_transient`) anywhere in this storage or its parent
+    /// chain.
+    ///
+    /// Only called on the cold "not found" path of `Container::get`, to tell
+    /// a genuinely-missing registration apart from one that exists but needs
+    /// `try_resolve` instead - the hot resolve path never pays for this check.
+    pub fn is_fallible_in_chain(&self, type_id: &TypeId) -> bool {
+        if self.factories.get(type_id).map(|f| f.is_fallible()).unwrap_or(false) {
+            return true;
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if storage.factories.get(type_id).map(|f| f.is_fallible()).unwrap_or(false) {
+                return true;
+            }
+            current = storage.parent.as_ref();
+        }
+
+        false
+    }
+
+    /// Resolve a fallible factory (`try_lazy`/`try_transient`) for `type_id`,
+    /// walking the full parent chain (nearest scope wins, matching
+    /// `resolve_from_chain`'s precedence). Returns `None` only if no fallible
+    /// registration for `type_id` exists anywhere in the chain - a registered
+    /// factory that fails still returns `Some(Err(_))`.
+    #[inline]
+    pub fn try_resolve_in_chain(
+        &self,
+        type_id: &TypeId,
+    ) -> Option<std::result::Result<Arc<dyn Any + Send + Sync>, crate::error::ResolveError>> {
+        if let Some(result) = self.factories.get(type_id).and_then(|f| f.try_resolve()) {
+            return Some(result);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(result) = storage.factories.get(type_id).and_then(|f| f.try_resolve()) {
+                return Some(result);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Check whether `type_id` is registered as an async-only factory
+    /// (`singleton_async`/`lazy_async`/`transient_async`) anywhere in this
+    /// storage or its parent chain.
+    ///
+    /// Only called on the cold "not found" path of `Container::get`, to
+    /// tell a genuinely-missing registration apart from one that exists but
+    /// needs `get_async` instead - the hot resolve path never pays for this
+    /// check.
+    #[cfg(feature = "async")]
+    pub fn is_async_in_chain(&self, type_id: &TypeId) -> bool {
+        if self.factories.get(type_id).map(|f| f.is_async()).unwrap_or(false) {
+            return true;
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if storage.factories.get(type_id).map(|f| f.is_async()).unwrap_or(false) {
+                return true;
+            }
+            current = storage.parent.as_ref();
+        }
+
+        false
+    }
+
+    /// Resolve an async-only factory for `type_id`, walking the full parent
+    /// chain (nearest scope wins, matching `resolve_from_chain`'s
+    /// precedence). Returns the cloned `Arc<AsyncFactory>` rather than an
+    /// already-awaited value, so the caller can `.await` it without holding
+    /// any `DashMap` guard.
+    #[cfg(feature = "async")]
+    pub fn async_factory_in_chain(&self, type_id: &TypeId) -> Option<Arc<AsyncFactory>> {
+        if let Some(factory) = self.factories.get(type_id).and_then(|f| f.async_factory()) {
+            return Some(factory);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(factory) = storage.factories.get(type_id).and_then(|f| f.async_factory()) {
+                return Some(factory);
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
+
+    /// Check whether `type_id` is registered as a pooled factory
+    /// (`Container::pooled`/`pooled_with_recycle`) anywhere in this storage
+    /// or its parent chain.
+    ///
+    /// Only called on the cold "not found" path of `Container::get`, to tell
+    /// a genuinely-missing registration apart from one that exists but needs
+    /// `get_pooled`/`get_pooled_timeout` instead - the hot resolve path never
+    /// pays for this check.
+    pub fn is_pooled_in_chain(&self, type_id: &TypeId) -> bool {
+        if self.factories.get(type_id).map(|f| f.is_pooled()).unwrap_or(false) {
+            return true;
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if storage.factories.get(type_id).map(|f| f.is_pooled()).unwrap_or(false) {
+                return true;
+            }
+            current = storage.parent.as_ref();
+        }
+
+        false
+    }
+
+    /// Resolve a pooled factory for `type_id`, walking the full parent chain
+    /// (nearest scope wins, matching `resolve_from_chain`'s precedence).
+    /// Returns the cloned `Arc<PooledFactory>` rather than a checked-out
+    /// instance, so the caller can check out (and later check back in)
+    /// without holding any `DashMap` guard across a potentially blocking
+    /// checkout.
+    #[inline]
+    pub fn pooled_factory_in_chain(&self, type_id: &TypeId) -> Option<Arc<crate::pool::PooledFactory>> {
+        if let Some(factory) = self.factories.get(type_id).and_then(|f| f.pooled_factory()) {
+            return Some(factory);
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(factory) = storage.factories.get(type_id).and_then(|f| f.pooled_factory()) {
+                return Some(factory);
+            }
+            current = storage.parent.as_ref();
         }
+
+        None
     }
 
-    /// Create with pre-allocated capacity and optimized shards.
+    /// Register an interface binding, keyed by `TypeId::of::<dyn Trait>()`.
     #[inline]
-    pub fn with_capacity(capacity: usize) -> Self {
-        // Scale shards based on expected capacity and concurrency needs
-        let shard_amount = if capacity <= 16 {
-            8
-        } else if capacity <= 64 {
-            16
-        } else {
-            32
-        };
-        Self {
-            factories: DashMap::with_capacity_and_hasher_and_shard_amount(
-                capacity,
-                RandomState::new(),
-                shard_amount,
-            ),
-            parent: None,
-        }
+    pub fn insert_interface(&self, trait_type_id: TypeId, resolver: InterfaceResolver) {
+        self.interfaces.insert(trait_type_id, resolver);
     }
 
-    /// Create a child storage with a parent reference for deep hierarchy resolution.
+    /// Append a factory to the per-type multi-registration list.
+    ///
+    /// Unlike `insert`, this never overwrites a prior registration for the
+    /// same `type_id` - every call adds another entry, supporting the
+    /// resolve-all pattern (e.g. collecting every registered `EventHandler`).
     #[inline]
-    pub fn with_parent(parent: Arc<ServiceStorage>) -> Self {
-        Self {
-            factories: DashMap::with_capacity_and_hasher_and_shard_amount(
-                0,
-                RandomState::new(),
-                8,
-            ),
-            parent: Some(parent),
-        }
+    pub fn append(&self, type_id: TypeId, factory: AnyFactory) {
+        self.multi.entry(type_id).or_insert_with(Vec::new).push(factory);
     }
 
-    /// Insert a factory
-    #[inline]
-    pub fn insert(&self, type_id: TypeId, factory: AnyFactory) {
-        self.factories.insert(type_id, factory);
+    /// Resolve every multi-registered factory for a type across this storage
+    /// and its full parent chain.
+    ///
+    /// Unlike `resolve_from_chain` (nearest-scope-wins), this merges entries
+    /// from every ancestor so a child scope sees all instances registered
+    /// anywhere in the hierarchy, ordered from the current scope outward.
+    /// Each factory is resolved through its own `Factory::resolve`, so a
+    /// `Lazy` or `Transient` entry keeps its lifetime semantics instead of
+    /// being forced into a single pre-built instance.
+    pub fn get_all_in_chain(&self, type_id: &TypeId) -> Vec<Arc<dyn Any + Send + Sync>> {
+        let mut all = Vec::new();
+        if let Some(entries) = self.multi.get(type_id) {
+            all.extend(entries.iter().map(|f| f.resolve()));
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(entries) = storage.multi.get(type_id) {
+                all.extend(entries.iter().map(|f| f.resolve()));
+            }
+            current = storage.parent.as_ref();
+        }
+
+        all
     }
 
-    /// Check if type exists
+    /// Resolve a trait-object interface binding, walking the full parent chain.
+    ///
+    /// Returns the type-erased `Arc<dyn Any>` wrapping an `Arc<dyn Trait>` produced
+    /// by whichever ancestor scope holds the binding (nearest scope wins).
     #[inline]
-    pub fn contains(&self, type_id: &TypeId) -> bool {
-        self.factories.contains_key(type_id)
+    pub fn resolve_interface(&self, trait_type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
+        if let Some(resolver) = self.interfaces.get(trait_type_id) {
+            if let Some(value) = resolver() {
+                return Some(value);
+            }
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(resolver) = storage.interfaces.get(trait_type_id) {
+                if let Some(value) = resolver() {
+                    return Some(value);
+                }
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
     }
 
-    /// Resolve a service by TypeId
+    /// Check if an interface binding exists in this storage or any parent.
     #[inline]
-    pub fn resolve(&self, type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
-        self.factories.get(type_id).map(|f| f.resolve())
+    pub fn contains_interface_in_chain(&self, trait_type_id: &TypeId) -> bool {
+        if self.interfaces.contains_key(trait_type_id) {
+            return true;
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if storage.interfaces.contains_key(trait_type_id) {
+                return true;
+            }
+            current = storage.parent.as_ref();
+        }
+
+        false
     }
 
-    /// Try to resolve and downcast to T
+    /// Append an already-coerced `Arc<dyn Trait>` (boxed as `Arc<dyn Any>`) to
+    /// the multi-binding list for `trait_type_id`.
     ///
-    /// Uses unchecked downcast since we know the type from the TypeId lookup.
+    /// Unlike `insert_interface`, this never overwrites a prior registration
+    /// for the same trait - every call adds another entry, mirroring
+    /// `append`'s accumulation for concrete types.
     #[inline]
-    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
-        self.resolve(&TypeId::of::<T>()).map(|any| {
-            // SAFETY: We looked up by TypeId::of::<T>(), so the factory
-            // was registered with the same TypeId and stores type T.
-            unsafe { downcast_arc_unchecked(any) }
-        })
+    pub fn append_interface(&self, trait_type_id: TypeId, instance: Arc<dyn Any + Send + Sync>) {
+        self.multi_interfaces.entry(trait_type_id).or_insert_with(Vec::new).push(instance);
     }
 
-    /// Resolve and return both the service and whether it's transient.
+    /// Resolve every multi-bound `Arc<dyn Trait>` for `trait_type_id` across
+    /// this storage and its full parent chain, ordered from the current
+    /// scope outward - mirroring `get_all_in_chain`'s precedence for a child
+    /// scope's extra bindings over its parent's.
+    pub fn resolve_all_interfaces_in_chain(&self, trait_type_id: &TypeId) -> Vec<Arc<dyn Any + Send + Sync>> {
+        let mut all = Vec::new();
+        if let Some(entries) = self.multi_interfaces.get(trait_type_id) {
+            all.extend(entries.iter().cloned());
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(entries) = storage.multi_interfaces.get(trait_type_id) {
+                all.extend(entries.iter().cloned());
+            }
+            current = storage.parent.as_ref();
+        }
+
+        all
+    }
+
+    /// Register a named `Arc<dyn Trait>` (boxed as `Arc<dyn Any>`) binding,
+    /// keyed by (`TypeId::of::<dyn Trait>()`, `name`).
     ///
-    /// This avoids a second DashMap lookup when checking if the service should be cached.
-    /// Returns `Some((service, is_transient))` if found, `None` if not found.
+    /// Unlike `append_interface`, a later call with the same trait and name
+    /// replaces the prior entry - this is the keyed single-slot counterpart
+    /// to `register_many`'s accumulating list.
     #[inline]
-    pub fn get_with_transient_flag<T: Send + Sync + 'static>(&self) -> Option<(Arc<T>, bool)> {
-        let type_id = TypeId::of::<T>();
-        self.factories.get(&type_id).map(|factory| {
-            let is_transient = factory.is_transient();
-            let service = factory.resolve();
-            // SAFETY: We looked up by TypeId::of::<T>(), so the factory stores type T.
-            let typed = unsafe { downcast_arc_unchecked(service) };
-            (typed, is_transient)
-        })
+    pub fn insert_named_interface(&self, trait_type_id: TypeId, name: &'static str, instance: Arc<dyn Any + Send + Sync>) {
+        self.named_interfaces.insert((trait_type_id, name), instance);
     }
 
-    /// Resolve a service by walking the full parent chain.
-    ///
-    /// Returns the service from the nearest scope that has it registered.
+    /// Resolve a named trait-object binding, walking the full parent chain
+    /// (nearest scope wins, matching `resolve_interface`'s precedence).
     #[inline]
-    pub fn resolve_from_chain(&self, type_id: &TypeId) -> Option<Arc<dyn Any + Send + Sync>> {
-        // Check current scope first
-        if let Some(service) = self.resolve(type_id) {
-            return Some(service);
+    pub fn resolve_named_interface_in_chain(&self, trait_type_id: &TypeId, name: &'static str) -> Option<Arc<dyn Any + Send + Sync>> {
+        if let Some(instance) = self.named_interfaces.get(&(*trait_type_id, name)) {
+            return Some(Arc::clone(&instance));
         }
 
-        // Walk parent chain
         let mut current = self.parent.as_ref();
         while let Some(storage) = current {
-            if let Some(service) = storage.resolve(type_id) {
-                return Some(service);
+            if let Some(instance) = storage.named_interfaces.get(&(*trait_type_id, name)) {
+                return Some(Arc::clone(&instance));
             }
             current = storage.parent.as_ref();
         }
@@ -175,24 +1547,36 @@ impl ServiceStorage {
         None
     }
 
-    /// Check if a service exists in this storage or any parent.
+    /// Register a keyed factory for `type_id`, replacing any prior
+    /// `register_keyed` registration for the same type - single-slot per
+    /// type, like `factories`, not accumulating like `multi`.
     #[inline]
-    pub fn contains_in_chain(&self, type_id: &TypeId) -> bool {
-        // Check current scope first
-        if self.contains(type_id) {
-            return true;
+    pub fn insert_keyed(&self, type_id: TypeId, registry: KeyedRegistry) {
+        self.keyed.insert(type_id, registry);
+    }
+
+    /// Resolve (memoizing on first sight) the instance for `key` under a
+    /// `register_keyed` registration for `type_id`, walking the full parent
+    /// chain (nearest scope wins, matching `resolve_interface`'s precedence).
+    #[inline]
+    pub fn keyed_instance_in_chain<K: Hash + Eq + 'static>(
+        &self,
+        type_id: &TypeId,
+        key: &K,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        if let Some(registry) = self.keyed.get(type_id) {
+            return Some(registry.get_or_create(key));
         }
 
-        // Walk parent chain
         let mut current = self.parent.as_ref();
         while let Some(storage) = current {
-            if storage.contains(type_id) {
-                return true;
+            if let Some(registry) = storage.keyed.get(type_id) {
+                return Some(registry.get_or_create(key));
             }
             current = storage.parent.as_ref();
         }
 
-        false
+        None
     }
 
     /// Get reference to parent storage (if any)
@@ -201,6 +1585,15 @@ impl ServiceStorage {
         self.parent.as_ref()
     }
 
+    /// This storage's unique "frame of reference" id, minted once at
+    /// creation. Used to stamp `ServiceKey`/`ServiceHandle` at issuance so a
+    /// lookup against a different storage can be rejected outright - see
+    /// `Container::get_by_key`/`get_by_handle`.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
     /// Create a child storage from this storage.
     ///
     /// This is more efficient than `with_parent` as it takes self by Arc reference.
@@ -212,7 +1605,22 @@ impl ServiceStorage {
                 RandomState::new(),
                 8,
             ),
+            interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            multi_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            named_interfaces: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            keyed: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            scoped: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            slab: new_slab(),
+            next_slab_shard: AtomicUsize::new(0),
             parent: Some(Arc::clone(self)),
+            disposers: std::sync::Mutex::new(Vec::new()),
+            cache: None,
+            epoch: AtomicU64::new(0),
+            fast_path: None,
+            handle_shards: new_handle_shards(),
+            handles: DashMap::with_capacity_and_hasher_and_shard_amount(0, RandomState::new(), 8),
+            id: next_storage_id(),
         }
     }
 
@@ -229,9 +1637,35 @@ impl ServiceStorage {
     }
 
     /// Clear all services (preserves parent reference)
+    ///
+    /// Runs any dispose hooks registered via `register_dispose` first, in
+    /// LIFO order, same as dropping this storage outright.
     #[inline]
     pub fn clear(&self) {
+        self.dispose_all();
         self.factories.clear();
+        self.epoch.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Register a dispose closure to run, in LIFO order with every other
+    /// disposer on this storage, when this storage is dropped or cleared.
+    ///
+    /// Does not itself store an instance - pair with `insert` (e.g. via
+    /// `AnyFactory::singleton`) so the dispose closure's captured `Arc` and
+    /// the resolvable factory entry refer to the same instance.
+    #[inline]
+    pub fn register_dispose(&self, dispose: Box<dyn FnOnce() + Send>) {
+        self.disposers.lock().unwrap().push(dispose);
+    }
+
+    /// Run every dispose hook registered on this storage (not its parent),
+    /// in reverse registration order, then forget them so they can't run
+    /// twice (e.g. once from an explicit `clear()`, again from `Drop`).
+    fn dispose_all(&self) {
+        let disposers = std::mem::take(&mut *self.disposers.lock().unwrap());
+        for dispose in disposers.into_iter().rev() {
+            dispose();
+        }
     }
 
     /// Check if this storage has a parent
@@ -241,9 +1675,25 @@ impl ServiceStorage {
     }
 
     /// Remove a service
+    ///
+    /// Also frees the type's handle slot, if it was registered via
+    /// `register_handle` - every `ServiceHandle<T>` still pointing at that
+    /// slot is invalidated for `resolve_by_handle`, matching `remove`'s
+    /// effect on plain `Container::get` resolution.
     #[inline]
     pub fn remove(&self, type_id: &TypeId) -> bool {
-        self.factories.remove(type_id).is_some()
+        let removed = self.factories.remove(type_id).is_some();
+        if let Some((_, packed)) = self.handles.remove(type_id) {
+            let handle = ServiceHandle::<()>::from_packed(packed);
+            let (shard, page, slot, _) = handle.unpack();
+            if let Some(shard) = self.handle_shards.get(shard) {
+                shard.free(page, slot);
+            }
+        }
+        if removed {
+            self.epoch.fetch_add(1, Ordering::Relaxed);
+        }
+        removed
     }
 
     /// Get all registered type IDs
@@ -251,6 +1701,27 @@ impl ServiceStorage {
         self.factories.iter().map(|r| *r.key()).collect()
     }
 
+    /// Type IDs of every `lazy` (not `try_lazy`/`autowired`/`singleton_async`)
+    /// registration on this storage - not its parent chain. Used by
+    /// `Container::warm_parallel` to find what to eagerly initialize.
+    pub fn lazy_type_ids(&self) -> Vec<TypeId> {
+        self.factories
+            .iter()
+            .filter(|r| matches!(**r.value(), AnyFactory::Lazy(_)))
+            .map(|r| *r.key())
+            .collect()
+    }
+
+    /// Does anything registered directly on this storage (not its parent)
+    /// still have a live `Arc` clone outstanding beyond the copy cached
+    /// here? Used by `ScopePool::release` to tell whether a scope is safe
+    /// to `clear()` and recycle immediately, or whether a caller is still
+    /// holding a resolved `Arc<T>` from it.
+    pub fn has_outstanding_refs(&self) -> bool {
+        self.factories.iter().any(|entry| entry.value().cached_strong_count() > 1)
+            || self.scoped.iter().any(|entry| Arc::strong_count(entry.value()) > 1)
+    }
+
     /// Check if a service is transient
     #[inline]
     pub fn is_transient(&self, type_id: &TypeId) -> bool {
@@ -259,6 +1730,25 @@ impl ServiceStorage {
             .map(|f| f.is_transient())
             .unwrap_or(false)
     }
+
+    /// Look up the `Lifetime` a service was registered with, walking the full
+    /// parent chain (nearest scope wins, matching `resolve`'s own precedence).
+    #[inline]
+    pub fn lifetime_in_chain(&self, type_id: &TypeId) -> Option<crate::Lifetime> {
+        if let Some(factory) = self.factories.get(type_id) {
+            return Some(factory.lifetime());
+        }
+
+        let mut current = self.parent.as_ref();
+        while let Some(storage) = current {
+            if let Some(factory) = storage.factories.get(type_id) {
+                return Some(factory.lifetime());
+            }
+            current = storage.parent.as_ref();
+        }
+
+        None
+    }
 }
 
 impl Default for ServiceStorage {
@@ -267,6 +1757,18 @@ impl Default for ServiceStorage {
     }
 }
 
+impl Drop for ServiceStorage {
+    /// Run this storage's own dispose hooks, in LIFO order, when the last
+    /// `Arc<ServiceStorage>` goes away (e.g. a `ScopedContainer` dropping).
+    ///
+    /// Never touches `parent` - that's a separate `Arc` with its own
+    /// refcount, so a child scope dropping only runs hooks it registered
+    /// itself, not its parent's.
+    fn drop(&mut self) {
+        self.dispose_all();
+    }
+}
+
 impl std::fmt::Debug for ServiceStorage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ServiceStorage")
@@ -303,7 +1805,7 @@ pub struct FrozenStorage {
     /// The perfect hash function
     mphf: boomphf::Mphf<HashableTypeId>,
     /// Factories indexed by perfect hash
-    factories: Vec<AnyFactory>,
+    factories: Vec<Arc<AnyFactory>>,
     /// TypeIds for verification (optional, can be removed for speed)
     type_ids: Vec<TypeId>,
     /// Parent storage for hierarchical resolution
@@ -318,7 +1820,7 @@ impl FrozenStorage {
     /// enabling O(1) lookups without hash collisions.
     pub fn from_storage(storage: &ServiceStorage) -> Self {
         // Collect all entries as owned values
-        let entries: Vec<(TypeId, AnyFactory)> = storage
+        let entries: Vec<(TypeId, Arc<AnyFactory>)> = storage
             .factories
             .iter()
             .map(|r| (*r.key(), r.value().clone()))
@@ -341,7 +1843,7 @@ impl FrozenStorage {
         let mphf = boomphf::Mphf::new(1.7, &hashable_ids);
 
         // Create factory and type_id arrays indexed by perfect hash
-        let mut factories: Vec<Option<AnyFactory>> = (0..n).map(|_| None).collect();
+        let mut factories: Vec<Option<Arc<AnyFactory>>> = (0..n).map(|_| None).collect();
         let mut indexed_type_ids: Vec<Option<TypeId>> = (0..n).map(|_| None).collect();
 
         for (type_id, factory) in entries {
@@ -351,7 +1853,7 @@ impl FrozenStorage {
         }
 
         // Unwrap all Options (all slots should be filled)
-        let factories: Vec<AnyFactory> = factories.into_iter().flatten().collect();
+        let factories: Vec<Arc<AnyFactory>> = factories.into_iter().flatten().collect();
         let type_ids: Vec<TypeId> = indexed_type_ids.into_iter().flatten().collect();
 
         // Freeze parent if it exists
@@ -437,6 +1939,33 @@ impl FrozenStorage {
         false
     }
 
+    /// Resolve several services in a single pass over the parent chain.
+    ///
+    /// Mirrors `ServiceStorage::resolve_many` - see there for the algorithm.
+    pub fn resolve_many(&self, ids: &[TypeId]) -> Vec<Option<Arc<dyn Any + Send + Sync>>> {
+        let mut results: Vec<Option<Arc<dyn Any + Send + Sync>>> = vec![None; ids.len()];
+        let mut pending: Vec<usize> = (0..ids.len()).collect();
+
+        let mut current = Some(self);
+        while let Some(storage) = current {
+            if pending.is_empty() {
+                break;
+            }
+
+            pending.retain(|&i| match storage.resolve(&ids[i]) {
+                Some(service) => {
+                    results[i] = Some(service);
+                    false
+                }
+                None => true,
+            });
+
+            current = storage.parent.as_deref();
+        }
+
+        results
+    }
+
     /// Get the number of services.
     #[inline]
     pub fn len(&self) -> usize {
@@ -554,4 +2083,241 @@ mod tests {
         storage.remove(&type_id);
         assert!(!storage.contains(&type_id));
     }
+
+    #[test]
+    fn test_lifetime_in_chain_local() {
+        let storage = ServiceStorage::new();
+        let type_id = TypeId::of::<TestService>();
+
+        assert_eq!(storage.lifetime_in_chain(&type_id), None);
+
+        storage.insert(type_id, AnyFactory::transient(|| TestService { value: 0 }));
+        assert_eq!(storage.lifetime_in_chain(&type_id), Some(crate::Lifetime::Transient));
+    }
+
+    #[test]
+    fn test_lifetime_in_chain_walks_parent() {
+        let parent = Arc::new(ServiceStorage::new());
+        let type_id = TypeId::of::<TestService>();
+        parent.insert(type_id, AnyFactory::lazy(|| TestService { value: 0 }));
+
+        let child = ServiceStorage::with_parent(Arc::clone(&parent));
+        assert_eq!(child.lifetime_in_chain(&type_id), Some(crate::Lifetime::Lazy));
+    }
+
+    #[test]
+    fn test_with_fast_path_cache_avoids_refactoring_non_transient() {
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+        let storage = ServiceStorage::with_fast_path_cache();
+        let type_id = TypeId::of::<TestService>();
+        storage.insert(
+            type_id,
+            AnyFactory::lazy(|| {
+                CREATED.fetch_add(1, Ordering::SeqCst);
+                TestService { value: 1 }
+            }),
+        );
+
+        let _ = storage.resolve(&type_id).unwrap();
+        let _ = storage.resolve(&type_id).unwrap();
+
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_with_fast_path_cache_does_not_cache_transient() {
+        let storage = ServiceStorage::with_fast_path_cache();
+        let type_id = TypeId::of::<TestService>();
+        storage.insert(type_id, AnyFactory::transient(|| TestService { value: 0 }));
+
+        let _ = storage.resolve(&type_id).unwrap();
+        assert!(storage.fast_path.as_ref().unwrap().get(&type_id, storage.epoch.load(Ordering::Relaxed)).is_none());
+    }
+
+    #[test]
+    fn test_with_fast_path_cache_invalidated_by_re_registration() {
+        let storage = ServiceStorage::with_fast_path_cache();
+        let type_id = TypeId::of::<TestService>();
+        storage.insert(type_id, AnyFactory::singleton(TestService { value: 1 }));
+
+        let first = storage.resolve(&type_id).unwrap();
+        assert_eq!(unsafe { downcast_arc_unchecked::<TestService>(first) }.value, 1);
+
+        // Re-registering bumps the epoch, so the stale fast-path entry is skipped.
+        storage.insert(type_id, AnyFactory::singleton(TestService { value: 2 }));
+        let second = storage.resolve(&type_id).unwrap();
+        assert_eq!(unsafe { downcast_arc_unchecked::<TestService>(second) }.value, 2);
+    }
+
+    #[test]
+    fn test_resolve_many_returns_positionally_aligned_results() {
+        let parent = Arc::new(ServiceStorage::new());
+        let a = TypeId::of::<TestService>();
+        let b = TypeId::of::<i32>();
+        let c = TypeId::of::<u64>();
+        parent.insert(a, AnyFactory::singleton(TestService { value: 1 }));
+
+        let child = ServiceStorage::with_parent(Arc::clone(&parent));
+        child.insert(b, AnyFactory::singleton(2i32));
+        // `c` is never registered.
+
+        let results = child.resolve_many(&[a, b, c]);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_some());
+        assert!(results[1].is_some());
+        assert!(results[2].is_none());
+    }
+
+    #[test]
+    fn test_hash_map_cache_get_insert_remove_clear() {
+        let mut cache = HashMapCache::new();
+        let type_id = TypeId::of::<TestService>();
+        let instance: Arc<dyn Any + Send + Sync> = Arc::new(TestService { value: 7 });
+
+        assert!(cache.get(&type_id).is_none());
+
+        cache.insert(type_id, Arc::clone(&instance));
+        assert!(cache.get(&type_id).is_some());
+
+        cache.remove(&type_id);
+        assert!(cache.get(&type_id).is_none());
+
+        cache.insert(type_id, instance);
+        cache.clear();
+        assert!(cache.get(&type_id).is_none());
+    }
+
+    #[test]
+    fn test_lru_cache_evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        let a = TypeId::of::<TestService>();
+        let b = TypeId::of::<i32>();
+        let c = TypeId::of::<u64>();
+
+        cache.insert(a, Arc::new(TestService { value: 1 }));
+        cache.insert(b, Arc::new(2i32));
+
+        // Touch `a` so `b` becomes the least-recently-used entry.
+        assert!(cache.get(&a).is_some());
+
+        // Inserting a third key should evict `b`, not `a`.
+        cache.insert(c, Arc::new(3u64));
+
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+    }
+
+    #[test]
+    fn test_storage_with_cache_caches_non_transient_resolve() {
+        static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+        let storage = ServiceStorage::with_cache(&HashMapCacheFactory);
+        let type_id = TypeId::of::<TestService>();
+        storage.insert(
+            type_id,
+            AnyFactory::lazy(|| {
+                CREATED.fetch_add(1, Ordering::SeqCst);
+                TestService { value: 1 }
+            }),
+        );
+
+        let _ = storage.resolve(&type_id).unwrap();
+        let _ = storage.resolve(&type_id).unwrap();
+
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_storage_with_cache_does_not_cache_transient() {
+        let storage = ServiceStorage::with_cache(&HashMapCacheFactory);
+        let type_id = TypeId::of::<TestService>();
+        storage.insert(type_id, AnyFactory::transient(|| TestService { value: 0 }));
+
+        let _ = storage.resolve(&type_id).unwrap();
+        assert!(storage.cache.as_ref().unwrap().lock().unwrap().get(&type_id).is_none());
+    }
+
+    #[test]
+    fn test_register_handle_resolves_by_handle() {
+        let storage = ServiceStorage::new();
+        let type_id = TypeId::of::<TestService>();
+        let handle = storage.register_handle::<TestService>(
+            type_id,
+            AnyFactory::singleton(TestService { value: 42 }),
+        );
+
+        let any = storage.resolve_by_handle(handle).unwrap();
+        assert_eq!(unsafe { downcast_arc_unchecked::<TestService>(any) }.value, 42);
+    }
+
+    #[test]
+    fn test_resolve_by_handle_rejects_stale_handle_after_removal() {
+        let storage = ServiceStorage::new();
+        let type_id = TypeId::of::<TestService>();
+        let handle = storage.register_handle::<TestService>(
+            type_id,
+            AnyFactory::singleton(TestService { value: 1 }),
+        );
+
+        assert!(storage.remove(&type_id));
+        assert!(storage.resolve_by_handle(handle).is_none());
+    }
+
+    #[test]
+    fn test_register_handle_reuses_freed_slot_with_new_generation() {
+        let storage = ServiceStorage::new();
+        let a = TypeId::of::<TestService>();
+        let b = TypeId::of::<i32>();
+
+        let first = storage.register_handle::<TestService>(
+            a,
+            AnyFactory::singleton(TestService { value: 1 }),
+        );
+        storage.remove(&a);
+
+        let second = storage.register_handle::<i32>(b, AnyFactory::singleton(2i32));
+
+        // The stale handle from before the `remove` must not resolve whatever
+        // reused its slot afterward.
+        assert!(storage.resolve_by_handle(first).is_none());
+        let any = storage.resolve_by_handle(second).unwrap();
+        assert_eq!(*unsafe { downcast_arc_unchecked::<i32>(any) }, 2);
+    }
+
+    #[test]
+    fn test_register_handle_grows_beyond_one_page() {
+        let storage = ServiceStorage::new();
+        let mut handles = Vec::new();
+        for i in 0..(HANDLE_PAGE_SLOTS * 2 + 5) {
+            let type_id = TypeId::of::<u64>();
+            let handle =
+                storage.register_handle::<u64>(type_id, AnyFactory::singleton(i as u64));
+            handles.push((handle, i as u64));
+        }
+
+        for (handle, expected) in handles {
+            let any = storage.resolve_by_handle(handle).unwrap();
+            assert_eq!(*unsafe { downcast_arc_unchecked::<u64>(any) }, expected);
+        }
+    }
+
+    #[test]
+    fn test_storage_id_is_unique_per_instance() {
+        let a = ServiceStorage::new();
+        let b = ServiceStorage::new();
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn test_handle_storage_id_matches_issuing_storage() {
+        let storage = ServiceStorage::new();
+        let handle = storage.register_handle::<TestService>(
+            TypeId::of::<TestService>(),
+            AnyFactory::singleton(TestService { value: 1 }),
+        );
+
+        assert_eq!(handle.storage_id(), storage.id());
+    }
 }
@@ -0,0 +1,128 @@
+//! Threading-mode abstraction (parallel vs. local), modeled on rustc's
+//! `sync` module.
+//!
+//! Every `Container` in this crate runs in "parallel" mode today: services
+//! are `Arc`-counted, `Send + Sync`, and the lock bit is an `AtomicBool`.
+//! That's the only mode [`Container`](crate::Container) actually uses in
+//! this version - [`ThreadingMode`] is the seam a future single-threaded
+//! `Container<Local>` would plug into, factored out now so that migration
+//! doesn't require touching every `Arc`/`AtomicBool` call site at once.
+//!
+//! [`Local`] is provided and exercised by its own tests below, but nothing
+//! in `Container`/`ServiceStorage` is generic over `ThreadingMode` yet -
+//! they're hard-wired to [`Parallel`]. Making them generic, and relaxing
+//! `Injectable`'s `Send + Sync` bound under `Local`, is follow-up work, not
+//! done here.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Selects the pointer and lock primitives a `Container` is built from.
+///
+/// Sealed: [`Parallel`] and [`Local`] are the only modes, since nothing is
+/// generic over this trait yet and there would be nowhere for a third
+/// implementation to plug in.
+pub trait ThreadingMode: private::Sealed + Default {
+    /// Reference-counted handle type used for shared service instances -
+    /// `Arc` under [`Parallel`], `Rc` under [`Local`].
+    type Handle<T: ?Sized>: Clone;
+
+    /// Interior-mutable lock bit used for `Container::lock`/
+    /// `check_not_locked` - `AtomicBool` under [`Parallel`], `Cell<bool>`
+    /// under [`Local`].
+    type LockFlag: Default;
+
+    /// Wrap a value in this mode's handle type.
+    fn new_handle<T>(value: T) -> Self::Handle<T>;
+
+    /// Read the current lock state.
+    fn get_locked(flag: &Self::LockFlag) -> bool;
+
+    /// Set the lock state.
+    fn set_locked(flag: &Self::LockFlag, locked: bool);
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::Parallel {}
+    impl Sealed for super::Local {}
+}
+
+/// Today's mode: `Arc` + `AtomicBool`, `Send + Sync` service handles. The
+/// only mode `Container` actually runs in.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Parallel;
+
+impl ThreadingMode for Parallel {
+    type Handle<T: ?Sized> = Arc<T>;
+    type LockFlag = AtomicBool;
+
+    #[inline]
+    fn new_handle<T>(value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    #[inline]
+    fn get_locked(flag: &AtomicBool) -> bool {
+        flag.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    fn set_locked(flag: &AtomicBool, locked: bool) {
+        flag.store(locked, Ordering::Release);
+    }
+}
+
+/// Single-threaded mode: `Rc` + `Cell<bool>`, no `Send + Sync` requirement
+/// on service handles. Not yet wired into `Container` - see module docs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Local;
+
+impl ThreadingMode for Local {
+    type Handle<T: ?Sized> = Rc<T>;
+    type LockFlag = Cell<bool>;
+
+    #[inline]
+    fn new_handle<T>(value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    #[inline]
+    fn get_locked(flag: &Cell<bool>) -> bool {
+        flag.get()
+    }
+
+    #[inline]
+    fn set_locked(flag: &Cell<bool>, locked: bool) {
+        flag.set(locked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parallel_handle_is_arc_and_lock_flag_round_trips() {
+        let handle = Parallel::new_handle(5_i32);
+        assert_eq!(*handle, 5);
+
+        let flag = AtomicBool::default();
+        assert!(!Parallel::get_locked(&flag));
+        Parallel::set_locked(&flag, true);
+        assert!(Parallel::get_locked(&flag));
+    }
+
+    #[test]
+    fn test_local_handle_is_rc_and_lock_flag_round_trips() {
+        let handle = Local::new_handle(5_i32);
+        assert_eq!(*handle, 5);
+
+        let flag = Cell::default();
+        assert!(!Local::get_locked(&flag));
+        Local::set_locked(&flag, true);
+        assert!(Local::get_locked(&flag));
+    }
+}
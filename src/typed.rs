@@ -57,6 +57,8 @@
 //! ```
 
 use crate::{Container, Injectable};
+use std::any::TypeId;
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -72,6 +74,61 @@ pub trait HasType<T: Injectable> {}
 
 impl<T: Injectable, Rest> HasType<T> for Reg<T, Rest> {}
 
+/// Marker for a trait object bound in the builder's registry via
+/// [`TypedBuilder::bind`].
+///
+/// Mirrors `Reg<T, Rest>`, but keys off the bound `Trait` rather than a
+/// concrete `T` - `Trait` is itself unsized (`dyn Greeter`), so this can't
+/// reuse `Reg` directly.
+pub struct RegTrait<Trait: ?Sized, Rest>(PhantomData<Trait>, PhantomData<Rest>);
+
+/// Trait for checking if trait object `Trait` is bound at the head of a registry.
+pub trait HasTrait<Trait: ?Sized> {}
+
+impl<Trait: ?Sized, Rest> HasTrait<Trait> for RegTrait<Trait, Rest> {}
+
+// =============================================================================
+// Conditional ("when") Bindings
+// =============================================================================
+
+/// A predicate evaluated against a [`ResolveContext`] to pick which
+/// `singleton_when` candidate wins at resolve time.
+type Predicate = Box<dyn Fn(&ResolveContext) -> bool + Send + Sync>;
+
+/// Named tags carried through to [`TypedBuilder::singleton_when`] predicates,
+/// e.g. the current environment or tenant.
+///
+/// ```rust
+/// use dependency_injector::typed::ResolveContext;
+///
+/// let ctx = ResolveContext::new().with_tag("env", "production");
+/// assert_eq!(ctx.tag("env"), Some("production"));
+/// assert_eq!(ctx.tag("missing"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResolveContext {
+    tags: HashMap<String, String>,
+}
+
+impl ResolveContext {
+    /// Create an empty context with no tags set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a tag, chaining for convenient construction.
+    #[must_use]
+    pub fn with_tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Look up a previously-set tag.
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+}
+
 // =============================================================================
 // Type-State Builder
 // =============================================================================
@@ -82,6 +139,16 @@ impl<T: Injectable, Rest> HasType<T> for Reg<T, Rest> {}
 pub struct TypedBuilder<R = ()> {
     container: Container,
     _registry: PhantomData<R>,
+    /// `(service, declared dependency names)` for every registration so far,
+    /// in registration order - fed to `try_build`'s cycle/missing-dependency
+    /// check. Plain `singleton`/`lazy`/`transient` registrations record an
+    /// empty dependency list; `with_deps`/`lazy_with_deps` overwrite that
+    /// entry with the real one from `DeclaresDeps::dependency_names()`.
+    edges: Vec<(&'static str, Vec<&'static str>)>,
+    /// Predicates for every `singleton_when` candidate, keyed by `TypeId`
+    /// and kept in the same order `Container::append` stores the matching
+    /// instances in - `TypedContainer::get_with` zips the two lists together.
+    conditionals: HashMap<TypeId, Vec<Predicate>>,
 }
 
 impl TypedBuilder<()> {
@@ -91,6 +158,8 @@ impl TypedBuilder<()> {
         Self {
             container: Container::new(),
             _registry: PhantomData,
+            edges: Vec::new(),
+            conditionals: HashMap::new(),
         }
     }
 
@@ -100,8 +169,33 @@ impl TypedBuilder<()> {
         Self {
             container: Container::with_capacity(capacity),
             _registry: PhantomData,
+            edges: Vec::new(),
+            conditionals: HashMap::new(),
         }
     }
+
+    /// Build a (dynamically-typed) `TypedBuilder` from a
+    /// [`ConfigRegistry`](crate::registry::ConfigRegistry) and a deserialized
+    /// JSON entry list, instead of a hand-written `.singleton(...)` chain.
+    ///
+    /// Since each entry's concrete type is picked at runtime by its `type`
+    /// tag, the returned builder's registry can't track what was installed -
+    /// it comes back as `TypedBuilder<()>`, the same starting point as
+    /// `TypedBuilder::new()`. Further `.singleton(...)`/`.bind::<dyn _>()`
+    /// calls can still be chained on top before `.build()`.
+    ///
+    /// Requires the `config` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` under the same conditions as
+    /// `Container::compose_from_config`.
+    #[cfg(feature = "config")]
+    pub fn from_config(registry: &crate::registry::ConfigRegistry, config: &str) -> crate::Result<TypedBuilder<()>> {
+        let builder = Self::new();
+        builder.container.compose_from_config(registry, config)?;
+        Ok(builder)
+    }
 }
 
 impl Default for TypedBuilder<()> {
@@ -115,9 +209,13 @@ impl<R> TypedBuilder<R> {
     #[inline]
     pub fn singleton<T: Injectable>(self, instance: T) -> TypedBuilder<Reg<T, R>> {
         self.container.singleton(instance);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
         TypedBuilder {
             container: self.container,
             _registry: PhantomData,
+            edges,
+            conditionals: self.conditionals,
         }
     }
 
@@ -128,9 +226,13 @@ impl<R> TypedBuilder<R> {
         F: Fn() -> T + Send + Sync + 'static,
     {
         self.container.lazy(factory);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
         TypedBuilder {
             container: self.container,
             _registry: PhantomData,
+            edges,
+            conditionals: self.conditionals,
         }
     }
 
@@ -141,9 +243,153 @@ impl<R> TypedBuilder<R> {
         F: Fn() -> T + Send + Sync + 'static,
     {
         self.container.transient(factory);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
+        TypedBuilder {
+            container: self.container,
+            _registry: PhantomData,
+            edges,
+            conditionals: self.conditionals,
+        }
+    }
+
+    /// Register a singleton candidate that only wins resolution when
+    /// `predicate` matches the [`ResolveContext`] passed to
+    /// `TypedContainer::get_with::<T>()`.
+    ///
+    /// Multiple `singleton_when` calls for the same `T` are tried in
+    /// registration order; the first matching predicate wins. If none
+    /// match, `get_with` falls back to a plain `.singleton()`/`.lazy()`/
+    /// `.transient()` registration for `T`, if one was made.
+    ///
+    /// ```rust
+    /// use dependency_injector::typed::{ResolveContext, TypedBuilder};
+    ///
+    /// #[derive(Clone)]
+    /// struct Greeting(&'static str);
+    ///
+    /// let container = TypedBuilder::new()
+    ///     .singleton_when(|ctx| ctx.tag("env") == Some("prod"), Greeting("hello, customer"))
+    ///     .singleton_when(|ctx| ctx.tag("env") == Some("dev"), Greeting("hi, dev"))
+    ///     .build();
+    ///
+    /// let prod_ctx = ResolveContext::new().with_tag("env", "prod");
+    /// assert_eq!(container.get_with::<Greeting>(&prod_ctx).0, "hello, customer");
+    /// ```
+    #[inline]
+    pub fn singleton_when<T: Injectable>(
+        self,
+        predicate: impl Fn(&ResolveContext) -> bool + Send + Sync + 'static,
+        instance: T,
+    ) -> TypedBuilder<Reg<T, R>> {
+        self.container.append(instance);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
+        let mut conditionals = self.conditionals;
+        conditionals
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(Box::new(predicate));
+        TypedBuilder {
+            container: self.container,
+            _registry: PhantomData,
+            edges,
+            conditionals,
+        }
+    }
+
+    /// Register a lazy singleton produced by an async factory.
+    ///
+    /// Mirrors `Container::lazy_async` - the factory's future is awaited on
+    /// the first `TypedContainer::get_async::<T>()` call, then the instance
+    /// is cached; concurrent first-resolvers await the same in-flight future
+    /// rather than racing to construct their own.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn lazy_async<T, F, Fut>(self, factory: F) -> TypedBuilder<Reg<T, R>>
+    where
+        T: Injectable,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = T> + Send + 'static,
+    {
+        self.container.lazy_async(factory);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
+        TypedBuilder {
+            container: self.container,
+            _registry: PhantomData,
+            edges,
+            conditionals: self.conditionals,
+        }
+    }
+
+    /// Register a transient service produced by an async factory.
+    ///
+    /// Mirrors `Container::transient_async` - a fresh future is awaited on
+    /// every `TypedContainer::get_async::<T>()` call.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub fn transient_async<T, F, Fut>(self, factory: F) -> TypedBuilder<Reg<T, R>>
+    where
+        T: Injectable,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = T> + Send + 'static,
+    {
+        self.container.transient_async(factory);
+        let mut edges = self.edges;
+        edges.push((short_type_name::<T>(), Vec::new()));
         TypedBuilder {
             container: self.container,
             _registry: PhantomData,
+            edges,
+            conditionals: self.conditionals,
+        }
+    }
+
+    /// Start a fluent interface binding, read naturally as
+    /// `TypedBuilder::new().bind::<dyn Greeter>().to::<EnglishGreeter>(coerce)`.
+    ///
+    /// Sugar over `Container::bind`, like `Container::bind_trait` - the
+    /// `coerce` upcast is still required since stable Rust has no bound that
+    /// lets generic code perform the `Arc<Concrete> -> Arc<Trait>` unsizing
+    /// coercion itself. Unlike `Container::bind_trait`, the bound trait is
+    /// folded into the returned builder's registry as `RegTrait<Trait, R>`,
+    /// so a later `TypedContainer::get_dyn::<Trait>()` traces back to this
+    /// call the same way `get::<T>()` traces back to `singleton`/`lazy`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dependency_injector::typed::TypedBuilder;
+    /// use std::sync::Arc;
+    ///
+    /// trait Greeter: Send + Sync {
+    ///     fn greet(&self) -> String;
+    /// }
+    ///
+    /// #[derive(Clone)]
+    /// struct EnglishGreeter;
+    ///
+    /// impl Greeter for EnglishGreeter {
+    ///     fn greet(&self) -> String {
+    ///         "hello".into()
+    ///     }
+    /// }
+    ///
+    /// let container = TypedBuilder::new()
+    ///     .singleton(EnglishGreeter)
+    ///     .bind::<dyn Greeter>()
+    ///     .to::<EnglishGreeter>(|c| c as Arc<dyn Greeter>)
+    ///     .build();
+    ///
+    /// let greeter = container.get_dyn::<dyn Greeter>();
+    /// assert_eq!(greeter.greet(), "hello");
+    /// ```
+    #[inline]
+    pub fn bind<Trait: ?Sized + Send + Sync + 'static>(self) -> TypedInterfaceBinder<Trait, R> {
+        TypedInterfaceBinder {
+            builder: self,
+            _trait: PhantomData,
         }
     }
 
@@ -154,9 +400,60 @@ impl<R> TypedBuilder<R> {
         TypedContainer {
             container: self.container,
             _registry: PhantomData,
+            conditionals: Arc::new(self.conditionals),
         }
     }
 
+    /// Build the typed container, first verifying the dependency graph
+    /// declared via `with_deps`/`lazy_with_deps`.
+    ///
+    /// Walks every declared `(service, dependency)` edge with a DFS,
+    /// mirroring syrette's dependency-history approach: a recursion stack
+    /// tracks the chain currently being visited, and revisiting a node still
+    /// on that stack means a cycle - reported as [`BuildError::DetectedCircular`]
+    /// with the full path, e.g. `["A", "B", "C", "A"]`. A declared dependency
+    /// that was never registered with this builder is reported as
+    /// [`BuildError::MissingDependency`] instead of surfacing only once
+    /// something tries to resolve it.
+    ///
+    /// Plain `singleton`/`lazy`/`transient` registrations declare no
+    /// dependencies and can never participate in a cycle - this only catches
+    /// graphs built from `with_deps`/`lazy_with_deps`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::DetectedCircular`] or [`BuildError::MissingDependency`]
+    /// if the declared graph is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use dependency_injector::typed::{BuildError, DeclaresDeps, TypedBuilder};
+    ///
+    /// #[derive(Clone)]
+    /// struct A;
+    /// #[derive(Clone)]
+    /// struct B;
+    ///
+    /// impl DeclaresDeps for A {
+    ///     fn dependency_names() -> &'static [&'static str] { &["B"] }
+    /// }
+    /// impl DeclaresDeps for B {
+    ///     fn dependency_names() -> &'static [&'static str] { &["A"] }
+    /// }
+    ///
+    /// let result = TypedBuilder::new()
+    ///     .with_deps(A)
+    ///     .with_deps(B)
+    ///     .try_build();
+    ///
+    /// assert!(matches!(result, Err(BuildError::DetectedCircular { .. })));
+    /// ```
+    pub fn try_build(self) -> std::result::Result<TypedContainer<R>, BuildError> {
+        verify_graph(&self.edges)?;
+        Ok(self.build())
+    }
+
     /// Build and return the underlying container.
     #[inline]
     pub fn build_dynamic(self) -> Container {
@@ -171,6 +468,144 @@ impl<R> TypedBuilder<R> {
     }
 }
 
+/// Fluent interface-binding builder returned by [`TypedBuilder::bind`].
+///
+/// Holds only the trait to bind until `to::<Concrete>` supplies the
+/// implementation and the `Arc<Concrete> -> Arc<Trait>` upcast - mirrors
+/// `container::InterfaceBinder`, but threads the type-state registry `R`
+/// through so `to` can fold the binding into it.
+pub struct TypedInterfaceBinder<Trait: ?Sized, R> {
+    builder: TypedBuilder<R>,
+    _trait: PhantomData<Trait>,
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static, R> TypedInterfaceBinder<Trait, R> {
+    /// Bind `Concrete` as the implementation, via the same `coerce` upcast
+    /// `Container::bind` takes.
+    #[inline]
+    pub fn to<Concrete>(
+        self,
+        coerce: impl Fn(Arc<Concrete>) -> Arc<Trait> + Send + Sync + 'static,
+    ) -> TypedBuilder<RegTrait<Trait, R>>
+    where
+        Concrete: Injectable,
+    {
+        self.builder.container.bind::<Trait, Concrete>(coerce);
+        TypedBuilder {
+            container: self.builder.container,
+            _registry: PhantomData,
+            edges: self.builder.edges,
+            conditionals: self.builder.conditionals,
+        }
+    }
+}
+
+// =============================================================================
+// Build-Time Graph Verification
+// =============================================================================
+
+/// The short (unqualified) name of `T`, e.g. `"Database"` for
+/// `some_crate::module::Database` - matches the identifiers
+/// `DeclaresDeps::dependency_names` is documented to use, since
+/// `std::any::type_name`'s full module path would never match those.
+fn short_type_name<T: ?Sized>() -> &'static str {
+    std::any::type_name::<T>().rsplit("::").next().unwrap()
+}
+
+/// Error from [`TypedBuilder::try_build`] - a problem found while verifying
+/// the dependency graph declared via `with_deps`/`lazy_with_deps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// A cycle was found among declared dependencies. `path` lists the
+    /// services involved in dependency order, with the first entry repeated
+    /// at the end to show where it closes, e.g. `["A", "B", "C", "A"]`.
+    DetectedCircular { path: Vec<&'static str> },
+    /// `service` declared a dependency on `missing` via `dependency_names`,
+    /// but nothing was ever registered under that name with this builder.
+    MissingDependency {
+        service: &'static str,
+        missing: &'static str,
+    },
+}
+
+impl std::fmt::Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DetectedCircular { path } => {
+                write!(f, "circular dependency detected: {}", path.join(" -> "))
+            }
+            Self::MissingDependency { service, missing } => {
+                write!(f, "`{service}` declares a dependency on `{missing}`, but `{missing}` was never registered")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Whether a node is mid-visit (on the current DFS recursion stack) or
+/// fully resolved - tracks the same "dependency history" syrette's cycle
+/// detector keeps.
+enum Mark {
+    Visiting,
+    Done,
+}
+
+/// DFS cycle check + registered-dependency check over the `(service, deps)`
+/// edges `TypedBuilder` accumulates - see `TypedBuilder::try_build`.
+fn verify_graph(edges: &[(&'static str, Vec<&'static str>)]) -> std::result::Result<(), BuildError> {
+    use std::collections::HashMap;
+
+    // Later registrations of the same name win, so `with_deps` overwriting
+    // the plain `(name, [])` edge `singleton` already pushed takes effect.
+    let mut graph: HashMap<&'static str, &[&'static str]> = HashMap::new();
+    for (name, deps) in edges {
+        graph.insert(name, deps.as_slice());
+    }
+
+    fn visit(
+        node: &'static str,
+        graph: &HashMap<&'static str, &[&'static str]>,
+        marks: &mut HashMap<&'static str, Mark>,
+        stack: &mut Vec<&'static str>,
+    ) -> std::result::Result<(), BuildError> {
+        match marks.get(node) {
+            Some(Mark::Done) => return Ok(()),
+            Some(Mark::Visiting) => {
+                let start = stack.iter().position(|&n| n == node).unwrap_or(0);
+                let mut path = stack[start..].to_vec();
+                path.push(node);
+                return Err(BuildError::DetectedCircular { path });
+            }
+            None => {}
+        }
+
+        marks.insert(node, Mark::Visiting);
+        stack.push(node);
+
+        for &dep in graph.get(node).copied().unwrap_or(&[]) {
+            if !graph.contains_key(dep) {
+                return Err(BuildError::MissingDependency {
+                    service: node,
+                    missing: dep,
+                });
+            }
+            visit(dep, graph, marks, stack)?;
+        }
+
+        stack.pop();
+        marks.insert(node, Mark::Done);
+        Ok(())
+    }
+
+    let mut marks: HashMap<&'static str, Mark> = HashMap::new();
+    let mut stack: Vec<&'static str> = Vec::new();
+    for (name, _) in edges {
+        visit(name, &graph, &mut marks, &mut stack)?;
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Dependency Declaration
 // =============================================================================
@@ -181,11 +616,14 @@ impl<R> TypedBuilder<R> {
 
 /// Trait for services that declare their dependencies.
 ///
-/// Use with `with_deps` to get documentation-level dependency declaration.
-/// Runtime verification ensures all dependencies are present.
+/// Use with `with_deps`/`lazy_with_deps` to have `TypedBuilder::try_build`
+/// verify the declared graph - a cycle or a dependency that was never
+/// registered is caught there instead of surfacing only once something
+/// tries to resolve it.
 ///
 /// Note: Full compile-time dependency verification requires proc macros
-/// or unstable Rust features. This provides a documentation/runtime hybrid.
+/// or unstable Rust features. Names are matched by their short (unqualified)
+/// form, e.g. `"Database"`, not `std::any::type_name`'s full module path.
 pub trait DeclaresDeps: Injectable {
     /// List of dependency type names (for documentation and debugging).
     fn dependency_names() -> &'static [&'static str] {
@@ -194,22 +632,25 @@ pub trait DeclaresDeps: Injectable {
 }
 
 impl<R> TypedBuilder<R> {
-    /// Register a service (alias for singleton with deps intent).
-    ///
-    /// Note: This method is the same as `singleton` but signals that
-    /// the service has dependencies that should already be registered.
+    /// Register a service, recording the dependencies it declares via
+    /// [`DeclaresDeps::dependency_names`] so `try_build` can verify them.
     #[inline]
     pub fn with_deps<T: DeclaresDeps>(self, instance: T) -> TypedBuilder<Reg<T, R>> {
-        self.singleton(instance)
+        let mut builder = self.singleton(instance);
+        builder.edges.push((short_type_name::<T>(), T::dependency_names().to_vec()));
+        builder
     }
 
-    /// Register a lazy service with deps intent.
+    /// Register a lazy service, recording the dependencies it declares via
+    /// [`DeclaresDeps::dependency_names`] so `try_build` can verify them.
     #[inline]
     pub fn lazy_with_deps<T: DeclaresDeps, F>(self, factory: F) -> TypedBuilder<Reg<T, R>>
     where
         F: Fn() -> T + Send + Sync + 'static,
     {
-        self.lazy(factory)
+        let mut builder = self.lazy(factory);
+        builder.edges.push((short_type_name::<T>(), T::dependency_names().to_vec()));
+        builder
     }
 }
 
@@ -228,6 +669,10 @@ impl<R, D> VerifyDeps<D> for R {}
 pub struct TypedContainer<R> {
     container: Container,
     _registry: PhantomData<R>,
+    /// Predicates for every `singleton_when` candidate, keyed by `TypeId` -
+    /// see `TypedBuilder::conditionals` and `get_with`. `Arc`-wrapped so
+    /// `Clone` stays cheap like `Container`'s own.
+    conditionals: Arc<HashMap<TypeId, Vec<Predicate>>>,
 }
 
 impl<R> TypedContainer<R> {
@@ -247,6 +692,68 @@ impl<R> TypedContainer<R> {
         self.container.try_get::<T>()
     }
 
+    /// Resolve a `singleton_when` conditional binding, picking the first
+    /// registered candidate whose predicate matches `ctx`.
+    ///
+    /// Falls back to a plain `get::<T>()` if no candidate's predicate
+    /// matches (or none were registered via `singleton_when` at all).
+    ///
+    /// # Panics
+    ///
+    /// Panics if no candidate matches and no plain binding for `T` exists
+    /// either - mirrors `get::<T>()`'s panic-on-missing behavior.
+    #[inline]
+    pub fn get_with<T: Injectable>(&self, ctx: &ResolveContext) -> Arc<T> {
+        if let Some(predicates) = self.conditionals.get(&TypeId::of::<T>()) {
+            let candidates = self.container.get_all::<T>();
+            for (candidate, predicate) in candidates.iter().zip(predicates.iter()) {
+                if predicate(ctx) {
+                    return Arc::clone(candidate);
+                }
+            }
+        }
+        self.get::<T>()
+    }
+
+    /// Resolve a service registered via `lazy_async`/`transient_async`,
+    /// awaiting its factory instead of requiring a value to already exist.
+    ///
+    /// Mirrors `Container::get_async`.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn get_async<T: Injectable>(&self) -> Arc<T> {
+        self.container
+            .get_async::<T>()
+            .await
+            .expect("TypedContainer: async service not found (registration mismatch)")
+    }
+
+    /// Try to resolve a service registered via `lazy_async`/
+    /// `transient_async`, returning `None` instead of panicking if `T` was
+    /// never registered async.
+    #[cfg(feature = "async")]
+    #[inline]
+    pub async fn try_get_async<T: Injectable>(&self) -> Option<Arc<T>> {
+        self.container.try_get_async::<T>().await
+    }
+
+    /// Resolve a trait-object interface bound via `TypedBuilder::bind`.
+    ///
+    /// Uses the dynamic container's `get_dyn` internally but provides a
+    /// type-safe API, the same way `get::<T>()` wraps `Container::get`.
+    #[inline]
+    pub fn get_dyn<Trait: ?Sized + Send + Sync + 'static>(&self) -> Arc<Trait> {
+        self.container
+            .get_dyn::<Trait>()
+            .expect("TypedContainer: interface not bound (registration mismatch)")
+    }
+
+    /// Try to resolve a trait-object interface, returning `None` if unbound.
+    #[inline]
+    pub fn try_get_dyn<Trait: ?Sized + Send + Sync + 'static>(&self) -> Option<Arc<Trait>> {
+        self.container.try_get_dyn::<Trait>()
+    }
+
     /// Check if service exists.
     #[inline]
     pub fn contains<T: Injectable>(&self) -> bool {
@@ -277,6 +784,7 @@ impl<R> Clone for TypedContainer<R> {
         Self {
             container: self.container.clone(),
             _registry: PhantomData,
+            conditionals: Arc::clone(&self.conditionals),
         }
     }
 }
@@ -405,6 +913,69 @@ mod tests {
         let _ = container.get::<UserService>();
     }
 
+    #[test]
+    fn test_try_build_succeeds_for_valid_dependency_graph() {
+        let result = TypedBuilder::new()
+            .singleton(Database { url: "pg".into() })
+            .singleton(Cache { size: 100 })
+            .with_deps(UserService)
+            .try_build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_try_build_detects_circular_dependency() {
+        #[derive(Clone)]
+        struct A;
+        #[derive(Clone)]
+        struct B;
+
+        impl DeclaresDeps for A {
+            fn dependency_names() -> &'static [&'static str] {
+                &["B"]
+            }
+        }
+        impl DeclaresDeps for B {
+            fn dependency_names() -> &'static [&'static str] {
+                &["A"]
+            }
+        }
+
+        let result = TypedBuilder::new().with_deps(A).with_deps(B).try_build();
+
+        match result {
+            Err(BuildError::DetectedCircular { path }) => {
+                assert_eq!(path.first(), path.last());
+                assert!(path.contains(&"A"));
+                assert!(path.contains(&"B"));
+            }
+            other => panic!("expected DetectedCircular, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_try_build_detects_missing_dependency() {
+        #[derive(Clone)]
+        struct NeedsGhost;
+
+        impl DeclaresDeps for NeedsGhost {
+            fn dependency_names() -> &'static [&'static str] {
+                &["Ghost"]
+            }
+        }
+
+        let result = TypedBuilder::new().with_deps(NeedsGhost).try_build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            BuildError::MissingDependency {
+                service: "NeedsGhost",
+                missing: "Ghost",
+            }
+        );
+    }
+
     #[test]
     fn test_many_services() {
         #[derive(Clone)]
@@ -433,6 +1004,119 @@ mod tests {
         let _ = container.get::<S5>();
     }
 
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    #[derive(Clone)]
+    struct EnglishGreeter;
+
+    impl Greeter for EnglishGreeter {
+        fn greet(&self) -> String {
+            "hello".into()
+        }
+    }
+
+    #[test]
+    fn test_bind_trait_object() {
+        let container = TypedBuilder::new()
+            .singleton(EnglishGreeter)
+            .bind::<dyn Greeter>()
+            .to::<EnglishGreeter>(|c| c as Arc<dyn Greeter>)
+            .build();
+
+        let greeter = container.get_dyn::<dyn Greeter>();
+        assert_eq!(greeter.greet(), "hello");
+    }
+
+    #[test]
+    fn test_try_get_dyn_unbound_returns_none() {
+        let container = TypedBuilder::new().singleton(EnglishGreeter).build();
+
+        assert!(container.try_get_dyn::<dyn Greeter>().is_none());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_lazy_async_resolves_via_get_async() {
+        let container = TypedBuilder::new()
+            .lazy_async(|| async { Database { url: "async://pool".into() } })
+            .build();
+
+        let db = container.get_async::<Database>().await;
+        assert_eq!(db.url, "async://pool");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_transient_async_creates_new_instance_each_call() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        #[derive(Clone)]
+        struct AsyncCounter(u32);
+
+        let container = TypedBuilder::new()
+            .transient_async(|| async { AsyncCounter(COUNTER.fetch_add(1, Ordering::SeqCst)) })
+            .build();
+
+        let a = container.get_async::<AsyncCounter>().await;
+        let b = container.get_async::<AsyncCounter>().await;
+        assert_ne!(a.0, b.0);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_try_get_async_on_unregistered_type_returns_none() {
+        let container = TypedBuilder::new().singleton(Database { url: "test".into() }).build();
+
+        assert!(container.try_get_async::<Cache>().await.is_none());
+    }
+
+    #[test]
+    fn test_singleton_when_matching_predicate_wins() {
+        let container = TypedBuilder::new()
+            .singleton_when(|ctx| ctx.tag("env") == Some("prod"), Database { url: "prod-db".into() })
+            .singleton_when(|ctx| ctx.tag("env") == Some("dev"), Database { url: "dev-db".into() })
+            .build();
+
+        let prod_ctx = ResolveContext::new().with_tag("env", "prod");
+        assert_eq!(container.get_with::<Database>(&prod_ctx).url, "prod-db");
+
+        let dev_ctx = ResolveContext::new().with_tag("env", "dev");
+        assert_eq!(container.get_with::<Database>(&dev_ctx).url, "dev-db");
+    }
+
+    #[test]
+    fn test_singleton_when_falls_through_non_matching_candidates() {
+        let container = TypedBuilder::new()
+            .singleton_when(|ctx| ctx.tag("env") == Some("prod"), Database { url: "prod-db".into() })
+            .singleton_when(|_ctx| true, Database { url: "fallback-db".into() })
+            .build();
+
+        let dev_ctx = ResolveContext::new().with_tag("env", "dev");
+        assert_eq!(container.get_with::<Database>(&dev_ctx).url, "fallback-db");
+    }
+
+    #[test]
+    fn test_get_with_falls_back_to_plain_singleton_when_no_candidate_matches() {
+        let container = TypedBuilder::new()
+            .singleton(Database { url: "default-db".into() })
+            .build();
+
+        let ctx = ResolveContext::new().with_tag("env", "prod");
+        assert_eq!(container.get_with::<Database>(&ctx).url, "default-db");
+    }
+
+    #[test]
+    fn test_resolve_context_tag_lookup() {
+        let ctx = ResolveContext::new().with_tag("env", "prod").with_tag("tenant", "acme");
+        assert_eq!(ctx.tag("env"), Some("prod"));
+        assert_eq!(ctx.tag("tenant"), Some("acme"));
+        assert_eq!(ctx.tag("missing"), None);
+    }
+
     #[test]
     fn test_scope_from_typed() {
         let container = TypedBuilder::new()
@@ -51,6 +51,7 @@
 //! ```
 
 use crate::{Container, Injectable};
+use std::any::TypeId;
 use std::sync::Arc;
 
 // =============================================================================
@@ -68,6 +69,8 @@ use std::sync::Arc;
 /// - `Arc<T>` - Single required dependency
 /// - `(Arc<A>, Arc<B>)` - Multiple dependencies (tuples up to 12)
 /// - `Option<Arc<T>>` - Optional dependency
+/// - `Vec<Arc<T>>` - Every instance registered for `T` via `Container::append`
+/// - `Dyn<dyn Trait>` - A trait-object dependency bound via `Container::bind`
 ///
 /// # Example
 ///
@@ -105,7 +108,11 @@ pub trait Service: Injectable + Sized {
     /// The dependencies required to create this service.
     ///
     /// Use `()` for no dependencies, `Arc<T>` for one, or tuples for multiple.
-    type Dependencies: Resolvable;
+    ///
+    /// Also requires `DependencyInfo` (rather than just `Resolvable`) so that
+    /// `ServiceProvider::provide`/`provide_transient`/etc. can record this
+    /// service's declared edges for `Container::verify()`.
+    type Dependencies: Resolvable + DependencyInfo;
 
     /// Create a new instance given the resolved dependencies.
     fn create(deps: Self::Dependencies) -> Self;
@@ -120,8 +127,12 @@ pub trait Service: Injectable + Sized {
 /// This is automatically implemented for:
 /// - `()` - No dependencies
 /// - `Arc<T>` - Single service
-/// - Tuples of `Arc<T>` - Multiple services
+/// - Tuples of `Resolvable` elements (2-12 long) - Multiple services,
+///   possibly of different kinds (e.g. a plain `Arc<T>` alongside a
+///   `Named<Trait, K>` or `Dyn<Trait>`)
 /// - `Option<Arc<T>>` - Optional service
+/// - `Vec<Arc<T>>` - Every instance appended for `T` (see `Container::append`)
+/// - `Dyn<dyn Trait>` - A trait-object dependency (see `Container::bind`)
 pub trait Resolvable: Sized {
     /// Resolve this dependency from the container.
     ///
@@ -153,13 +164,173 @@ impl<T: Injectable> Resolvable for Option<Arc<T>> {
     }
 }
 
-// Tuple implementations (2-12 elements)
+// All multi-registered instances (resolve-all, see `Container::append`)
+impl<T: Injectable> Resolvable for Vec<Arc<T>> {
+    #[inline]
+    fn resolve(container: &Container) -> Option<Self> {
+        Some(container.get_all::<T>())
+    }
+}
+
+// =============================================================================
+// Dyn<Trait> - Trait-Object Dependencies
+// =============================================================================
+
+/// A resolved trait-object dependency, wrapping the `Arc<dyn Trait>` bound
+/// via `Container::bind`/`bind_interface`.
+///
+/// `Resolvable` already has a blanket impl for `Arc<T: Injectable>` keyed by
+/// a concrete `TypeId`. A second blanket impl directly for `Arc<dyn Trait>`
+/// would overlap it - every concrete `T: Injectable` also satisfies
+/// `Trait: ?Sized + Send + Sync + 'static`, so the compiler couldn't tell the
+/// two impls apart. `Dyn<Trait>` is a thin, `Deref`-transparent newtype that
+/// sidesteps the conflict: a `Service` declares
+/// `type Dependencies = Dyn<dyn Repository>` and uses it exactly like the
+/// `Arc<dyn Repository>` it wraps.
+///
+/// # Example
+///
+/// ```rust
+/// use dependency_injector::verified::{Dyn, Service, ServiceProvider};
+/// use dependency_injector::Container;
+/// use std::sync::Arc;
+///
+/// trait Repository: Send + Sync {
+///     fn find(&self) -> &str;
+/// }
+///
+/// #[derive(Clone)]
+/// struct PostgresRepository;
+///
+/// impl Repository for PostgresRepository {
+///     fn find(&self) -> &str { "row" }
+/// }
+///
+/// struct UserService {
+///     repo: Dyn<dyn Repository>,
+/// }
+///
+/// impl Service for UserService {
+///     type Dependencies = Dyn<dyn Repository>;
+///
+///     fn create(repo: Self::Dependencies) -> Self {
+///         UserService { repo }
+///     }
+/// }
+///
+/// let container = Container::new();
+/// container.singleton(PostgresRepository);
+/// container.bind::<dyn Repository, PostgresRepository>(|c| c as Arc<dyn Repository>);
+/// container.provide::<UserService>();
+///
+/// let svc = container.get::<UserService>().unwrap();
+/// assert_eq!(svc.repo.find(), "row");
+/// ```
+pub struct Dyn<Trait: ?Sized>(pub Arc<Trait>);
+
+impl<Trait: ?Sized> std::ops::Deref for Dyn<Trait> {
+    type Target = Trait;
+
+    #[inline]
+    fn deref(&self) -> &Trait {
+        &self.0
+    }
+}
+
+impl<Trait: ?Sized> Clone for Dyn<Trait> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Dyn(Arc::clone(&self.0))
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static> Resolvable for Dyn<Trait> {
+    #[inline]
+    fn resolve(container: &Container) -> Option<Self> {
+        container.try_get_dyn::<Trait>().map(Dyn)
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static> Resolvable for Option<Dyn<Trait>> {
+    #[inline]
+    fn resolve(container: &Container) -> Option<Self> {
+        Some(container.try_get_dyn::<Trait>().map(Dyn))
+    }
+}
+
+// =============================================================================
+// Named<Trait, K> - Keyed Trait-Object Dependencies
+// =============================================================================
+
+/// A compile-time key identifying which `register_named` entry a `Named<Trait, K>`
+/// dependency should resolve. Implemented by a zero-sized marker type the
+/// `#[derive(Service)]` macro generates for each `#[dep(name = "...")]` field -
+/// see `dependency-injector-derive`.
+pub trait NamedKey {
+    /// The name passed to `Container::register_named`/`get_named`.
+    const NAME: &'static str;
+}
+
+/// A resolved, named trait-object dependency, wrapping the `Arc<Trait>` bound
+/// via `Container::register_named::<Trait>(K::NAME, ...)`.
+///
+/// Mirrors `Dyn<Trait>`: a direct blanket impl of `Resolvable` for
+/// `Arc<Trait>` keyed only by `Trait` can't also carry a runtime name, so
+/// `Named<Trait, K>` threads the name through at the type level via `K`
+/// instead, letting `#[dep(name = "...")]` request one of several
+/// implementations of the same `Trait`.
+pub struct Named<Trait: ?Sized, K: NamedKey>(pub Arc<Trait>, std::marker::PhantomData<K>);
+
+impl<Trait: ?Sized, K: NamedKey> std::ops::Deref for Named<Trait, K> {
+    type Target = Trait;
+
+    #[inline]
+    fn deref(&self) -> &Trait {
+        &self.0
+    }
+}
+
+impl<Trait: ?Sized, K: NamedKey> Clone for Named<Trait, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Named(Arc::clone(&self.0), std::marker::PhantomData)
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static, K: NamedKey> Resolvable for Named<Trait, K> {
+    #[inline]
+    fn resolve(container: &Container) -> Option<Self> {
+        container
+            .resolve_named::<Trait>(K::NAME)
+            .map(|arc| Named(arc, std::marker::PhantomData))
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static, K: NamedKey> Resolvable for Option<Named<Trait, K>> {
+    #[inline]
+    fn resolve(container: &Container) -> Option<Self> {
+        Some(
+            container
+                .resolve_named::<Trait>(K::NAME)
+                .map(|arc| Named(arc, std::marker::PhantomData)),
+        )
+    }
+}
+
+// Tuple implementations (2-12 elements).
+//
+// Generic over `Resolvable` members (rather than hard-coding `Arc<$T>`) so a
+// `#[derive(Service)]` struct can mix dependency kinds - e.g. two
+// `#[dep(name = "...")]` fields, or a `#[dep(dyn)]` field alongside a plain
+// `#[dep]` one - in the same `Dependencies` tuple. Each element still only
+// needs its own `Resolvable` impl (`Arc<T>`, `Named<Trait, K>`, `Dyn<Trait>`,
+// their `Option<_>` forms, ...), resolved independently and in order.
 macro_rules! impl_resolvable_tuple {
     ($($T:ident),+) => {
-        impl<$($T: Injectable),+> Resolvable for ($(Arc<$T>,)+) {
+        impl<$($T: Resolvable),+> Resolvable for ($($T,)+) {
             #[inline]
             fn resolve(container: &Container) -> Option<Self> {
-                Some(($(container.try_get::<$T>()?,)+))
+                Some(($($T::resolve(container)?,)+))
             }
         }
     };
@@ -190,8 +361,11 @@ pub trait ServiceProvider {
     ///
     /// # Panics
     ///
-    /// The created factory will panic at runtime if dependencies are missing.
-    /// For compile-time safety, use the typed builder API.
+    /// The created factory will panic at runtime if dependencies are missing,
+    /// or if `T` (directly or transitively, through another autowired
+    /// service) ends up depending on itself - the same cycle guard
+    /// `Container::factory` uses. For compile-time safety, use the typed
+    /// builder API.
     ///
     /// # Example
     ///
@@ -227,40 +401,220 @@ pub trait ServiceProvider {
     ///
     /// A new instance is created on every resolution.
     fn provide_transient<T: Service>(&self);
+
+    /// Register a service as scoped.
+    ///
+    /// At most one instance is created per [`Container::child()`] scope,
+    /// reused for the rest of that scope's lifetime - see [`Container::scoped`].
+    fn provide_scoped<T: Service>(&self);
+
+    /// Construct a service and append it to `T`'s multi-registration list,
+    /// instead of replacing any prior registration.
+    ///
+    /// Dependencies are resolved immediately, like `provide_singleton`. Use
+    /// this (repeatedly, once per implementation) to build up a collection
+    /// resolved together as `Vec<Arc<T>>` - e.g. registering several
+    /// `EventHandler` implementations that a dispatcher resolves as a group.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all dependencies were resolved and the service was appended,
+    /// `false` if any dependency was missing.
+    fn provide_many<T: Service>(&self) -> bool;
+}
+
+/// Shared no-op `Container::init_all()` closure for registration kinds that
+/// have nothing useful to re-run: `provide`/`provide_transient`/`provide_scoped`
+/// are already either lazy-safe or lack a single eager instance, and
+/// `provide_many` would double-append if re-run after an earlier success.
+#[inline]
+fn no_op_init() -> Arc<dyn Fn(&Container) + Send + Sync> {
+    Arc::new(|_: &Container| {})
+}
+
+/// Push `T` onto the same per-thread resolution stack
+/// `#[derive(Inject)]`'s `from_container` uses (see `enter_resolution_frame`
+/// in `container.rs`), run `f`, then pop - so a `Service` that (directly or
+/// transitively) depends on itself is caught as a cycle instead of recursing
+/// through `T::Dependencies::resolve`/`T::create` until the stack overflows,
+/// and any `DiError::CreationFailed` raised while `f` runs captures the full
+/// chain of services under construction above it.
+///
+/// `provide`/`provide_transient`/`provide_scoped` register a closure that
+/// can't return `Result` (see `Container::lazy`/`transient`/`scoped`), so a
+/// cycle here panics with the same message `Container::factory` would
+/// surface - consistent with the `.expect(...)` already guarding a missing
+/// dependency on these same paths.
+#[inline]
+fn with_resolution_frame<T: Service, R>(f: impl FnOnce() -> R) -> R {
+    let _guard =
+        crate::container::push_resolution(TypeId::of::<T>(), std::any::type_name::<T>()).unwrap();
+    f()
 }
 
 impl ServiceProvider for Container {
     #[inline]
     fn provide<T: Service>(&self) {
+        self.record_dependency_node(
+            std::any::type_name::<T>(),
+            T::Dependencies::dependency_names(),
+            T::Dependencies::optional_dependency_names(),
+            no_op_init(),
+        );
+
         let container = self.clone();
         self.lazy(move || {
-            let deps = T::Dependencies::resolve(&container)
-                .expect("Failed to resolve dependencies for service");
-            T::create(deps)
+            with_resolution_frame::<T, _>(|| {
+                let deps = T::Dependencies::resolve(&container)
+                    .expect("Failed to resolve dependencies for service");
+                T::create(deps)
+            })
         });
     }
 
     #[inline]
     fn provide_singleton<T: Service>(&self) -> bool {
-        if let Some(deps) = T::Dependencies::resolve(self) {
-            self.singleton(T::create(deps));
-            true
-        } else {
-            false
-        }
+        self.record_dependency_node(
+            std::any::type_name::<T>(),
+            T::Dependencies::dependency_names(),
+            T::Dependencies::optional_dependency_names(),
+            Arc::new(|container: &Container| {
+                container.provide_singleton::<T>();
+            }),
+        );
+
+        with_resolution_frame::<T, _>(|| {
+            if let Some(deps) = T::Dependencies::resolve(self) {
+                self.singleton(T::create(deps));
+                true
+            } else {
+                false
+            }
+        })
     }
 
     #[inline]
     fn provide_transient<T: Service>(&self) {
+        self.record_dependency_node(
+            std::any::type_name::<T>(),
+            T::Dependencies::dependency_names(),
+            T::Dependencies::optional_dependency_names(),
+            no_op_init(),
+        );
+
         let container = self.clone();
         self.transient(move || {
-            let deps = T::Dependencies::resolve(&container)
-                .expect("Failed to resolve dependencies for transient service");
-            T::create(deps)
+            with_resolution_frame::<T, _>(|| {
+                let deps = T::Dependencies::resolve(&container)
+                    .expect("Failed to resolve dependencies for transient service");
+                T::create(deps)
+            })
         });
     }
+
+    #[inline]
+    fn provide_scoped<T: Service>(&self) {
+        self.record_dependency_node(
+            std::any::type_name::<T>(),
+            T::Dependencies::dependency_names(),
+            T::Dependencies::optional_dependency_names(),
+            no_op_init(),
+        );
+
+        let container = self.clone();
+        self.scoped(move || {
+            with_resolution_frame::<T, _>(|| {
+                let deps = T::Dependencies::resolve(&container)
+                    .expect("Failed to resolve dependencies for scoped service");
+                T::create(deps)
+            })
+        });
+    }
+
+    #[inline]
+    fn provide_many<T: Service>(&self) -> bool {
+        self.record_dependency_node(
+            std::any::type_name::<T>(),
+            T::Dependencies::dependency_names(),
+            T::Dependencies::optional_dependency_names(),
+            no_op_init(),
+        );
+
+        with_resolution_frame::<T, _>(|| {
+            if let Some(deps) = T::Dependencies::resolve(self) {
+                self.append(T::create(deps));
+                true
+            } else {
+                false
+            }
+        })
+    }
 }
 
+// =============================================================================
+// ServiceFactory - Function-Based Constructors
+// =============================================================================
+
+/// A plain function or closure that constructs a service from arguments the
+/// container can resolve, backing [`Container::provide_fn`] so a free
+/// function can register as a constructor without a `#[derive(Service)]`
+/// struct impl.
+///
+/// Blanket-implemented for `Fn(Arc<A>, Arc<B>, ...) -> R` closures and
+/// function pointers of up to 12 arguments - mirrors the `Resolvable` tuple
+/// impls above, except each argument is resolved positionally instead of
+/// through an associated `Dependencies` type.
+///
+/// [`Container::provide_fn`]: crate::Container::provide_fn
+pub trait ServiceFactory<Args, R>: Send + Sync {
+    /// Resolve every argument from `container` and invoke this factory.
+    ///
+    /// # Errors
+    ///
+    /// Returns whichever `DiError` the first missing argument's
+    /// `container.get::<_>()` call produces.
+    fn invoke(&self, container: &Container) -> crate::Result<R>;
+}
+
+impl<F, R> ServiceFactory<(), R> for F
+where
+    F: Fn() -> R + Send + Sync,
+{
+    #[inline]
+    fn invoke(&self, _container: &Container) -> crate::Result<R> {
+        Ok((self)())
+    }
+}
+
+macro_rules! impl_service_factory {
+    ($($T:ident),+) => {
+        impl<Func, R, $($T: Injectable),+> ServiceFactory<($($T,)+), R> for Func
+        where
+            Func: Fn($(Arc<$T>),+) -> R + Send + Sync,
+        {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn invoke(&self, container: &Container) -> crate::Result<R> {
+                $(let $T = container.get::<$T>()?;)+
+                Ok((self)($($T),+))
+            }
+        }
+    };
+}
+
+impl_service_factory!(A);
+impl_service_factory!(A, B);
+impl_service_factory!(A, B, C);
+impl_service_factory!(A, B, C, D);
+impl_service_factory!(A, B, C, D, E);
+impl_service_factory!(A, B, C, D, E, F);
+impl_service_factory!(A, B, C, D, E, F, G);
+impl_service_factory!(A, B, C, D, E, F, G, H);
+impl_service_factory!(A, B, C, D, E, F, G, H, I);
+impl_service_factory!(A, B, C, D, E, F, G, H, I, J);
+impl_service_factory!(A, B, C, D, E, F, G, H, I, J, K);
+impl_service_factory!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 // =============================================================================
 // ServiceModule - Group related services
 // =============================================================================
@@ -306,6 +660,20 @@ impl ServiceProvider for Container {
 pub trait ServiceModule {
     /// Register all services in this module.
     fn register(container: &Container);
+
+    /// Register this module's services, then eagerly instantiate every
+    /// `provide_singleton` among them in dependency order via
+    /// [`Container::init_all`], instead of requiring `register` to call
+    /// `provide_singleton` in a pre-sorted sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::Cycle`] if the module's declared dependencies
+    /// don't form a valid order; see `Container::init_all`.
+    fn build(container: &Container) -> std::result::Result<(), crate::GraphError> {
+        Self::register(container);
+        container.init_all()
+    }
 }
 
 // =============================================================================
@@ -318,6 +686,17 @@ pub trait ServiceModule {
 pub trait DependencyInfo {
     /// Get the type names of all dependencies.
     fn dependency_names() -> Vec<&'static str>;
+
+    /// Get the subset of `dependency_names()` that resolve to an empty/`None`
+    /// value instead of an error when unregistered.
+    ///
+    /// `Container::verify()` uses this to tell a genuinely missing provider
+    /// (required dependency, no registration anywhere) from an `Option`/`Vec`
+    /// dependency that simply resolves empty - the latter is never an error.
+    /// Defaults to empty, which is correct for every required dependency kind.
+    fn optional_dependency_names() -> Vec<&'static str> {
+        Vec::new()
+    }
 }
 
 impl DependencyInfo for () {
@@ -336,14 +715,74 @@ impl<T: Injectable> DependencyInfo for Option<Arc<T>> {
     fn dependency_names() -> Vec<&'static str> {
         vec![std::any::type_name::<T>()]
     }
+
+    fn optional_dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<T>()]
+    }
+}
+
+impl<T: Injectable> DependencyInfo for Vec<Arc<T>> {
+    fn dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<T>()]
+    }
+
+    fn optional_dependency_names() -> Vec<&'static str> {
+        // An empty Vec is a valid resolution, not an error - see `get_all`.
+        vec![std::any::type_name::<T>()]
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static> DependencyInfo for Dyn<Trait> {
+    fn dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static> DependencyInfo for Option<Dyn<Trait>> {
+    fn dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
+
+    fn optional_dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static, K: NamedKey> DependencyInfo for Named<Trait, K> {
+    fn dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
+}
+
+impl<Trait: ?Sized + Send + Sync + 'static, K: NamedKey> DependencyInfo for Option<Named<Trait, K>> {
+    fn dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
+
+    fn optional_dependency_names() -> Vec<&'static str> {
+        vec![std::any::type_name::<Trait>()]
+    }
 }
 
-// Tuple implementations for DependencyInfo
+// Tuple implementations for DependencyInfo.
+//
+// Generic over `DependencyInfo` members for the same reason as
+// `impl_resolvable_tuple` above - a heterogeneous `Dependencies` tuple (mixed
+// `Arc<T>`/`Named<Trait, K>`/`Dyn<Trait>`/`Option<_>` elements) needs an
+// impl that doesn't assume every slot is a plain `Arc<$T>`.
 macro_rules! impl_dependency_info_tuple {
     ($($T:ident),+) => {
-        impl<$($T: Injectable),+> DependencyInfo for ($(Arc<$T>,)+) {
+        impl<$($T: DependencyInfo),+> DependencyInfo for ($($T,)+) {
             fn dependency_names() -> Vec<&'static str> {
-                vec![$(std::any::type_name::<$T>()),+]
+                let mut names = Vec::new();
+                $(names.extend($T::dependency_names());)+
+                names
+            }
+
+            fn optional_dependency_names() -> Vec<&'static str> {
+                let mut names = Vec::new();
+                $(names.extend($T::optional_dependency_names());)+
+                names
             }
         }
     };
@@ -354,6 +793,12 @@ impl_dependency_info_tuple!(A, B, C);
 impl_dependency_info_tuple!(A, B, C, D);
 impl_dependency_info_tuple!(A, B, C, D, E);
 impl_dependency_info_tuple!(A, B, C, D, E, F);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G, H);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G, H, I);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_dependency_info_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
 
 // =============================================================================
 // Compile-Time Cycle Detection (Documentation Only)
@@ -521,6 +966,36 @@ mod tests {
         assert_ne!(c1.0, c2.0);
     }
 
+    #[test]
+    fn test_provide_scoped() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+        #[derive(Clone)]
+        struct RequestId(u32);
+
+        impl Service for RequestId {
+            type Dependencies = ();
+
+            fn create(_: ()) -> Self {
+                RequestId(COUNTER.fetch_add(1, Ordering::SeqCst))
+            }
+        }
+
+        let root = Container::new();
+        root.provide_scoped::<RequestId>();
+
+        let request1 = root.child();
+        let a = request1.get::<RequestId>().unwrap();
+        let b = request1.get::<RequestId>().unwrap();
+        assert_eq!(a.0, b.0);
+
+        let request2 = root.child();
+        let c = request2.get::<RequestId>().unwrap();
+        assert_ne!(a.0, c.0);
+    }
+
     #[test]
     fn test_optional_dependency() {
         #[derive(Clone)]
@@ -587,5 +1062,235 @@ mod tests {
         assert!(container.contains::<Config>());
         assert!(container.contains::<Cache>());
     }
+
+    #[test]
+    fn test_service_module_build_orders_singletons() {
+        struct OrderModule;
+
+        impl ServiceModule for OrderModule {
+            fn register(container: &Container) {
+                // Registered out of dependency order - `Database` depends on
+                // `Config`, which isn't registered until after it.
+                container.provide_singleton::<Database>();
+                container.provide_singleton::<Config>();
+            }
+        }
+
+        let container = Container::new();
+        assert!(OrderModule::build(&container).is_ok());
+
+        let db = container.get::<Database>().unwrap();
+        assert_eq!(db.url, "debug://localhost");
+    }
+
+    #[derive(Clone)]
+    struct Plugin {
+        name: &'static str,
+    }
+
+    impl Service for Plugin {
+        type Dependencies = ();
+
+        fn create(_: ()) -> Self {
+            unreachable!("plugins in this test are constructed directly, not via create()")
+        }
+    }
+
+    #[derive(Clone)]
+    struct Dispatcher {
+        plugins: Vec<Arc<Plugin>>,
+    }
+
+    impl Service for Dispatcher {
+        type Dependencies = Vec<Arc<Plugin>>;
+
+        fn create(plugins: Vec<Arc<Plugin>>) -> Self {
+            Dispatcher { plugins }
+        }
+    }
+
+    #[test]
+    fn test_vec_dependency_resolves_all_appended() {
+        let container = Container::new();
+        container.append(Plugin { name: "a" });
+        container.append(Plugin { name: "b" });
+        container.provide::<Dispatcher>();
+
+        let dispatcher = container.get::<Dispatcher>().unwrap();
+        assert_eq!(dispatcher.plugins.len(), 2);
+    }
+
+    #[test]
+    fn test_vec_dependency_empty_when_nothing_appended() {
+        let container = Container::new();
+        container.provide::<Dispatcher>();
+
+        let dispatcher = container.get::<Dispatcher>().unwrap();
+        assert!(dispatcher.plugins.is_empty());
+    }
+
+    #[test]
+    fn test_provide_many_appends_instead_of_replacing() {
+        #[derive(Clone)]
+        struct NamedHandler {
+            name: &'static str,
+        }
+
+        impl Service for NamedHandler {
+            type Dependencies = ();
+            fn create(_: ()) -> Self {
+                NamedHandler { name: "handler" }
+            }
+        }
+
+        let container = Container::new();
+        assert!(container.provide_many::<NamedHandler>());
+        assert!(container.provide_many::<NamedHandler>());
+
+        assert_eq!(container.get_all::<NamedHandler>().len(), 2);
+    }
+
+    trait Repository: Send + Sync {
+        fn find(&self) -> &str;
+    }
+
+    #[derive(Clone)]
+    struct PostgresRepository;
+
+    impl Repository for PostgresRepository {
+        fn find(&self) -> &str {
+            "row"
+        }
+    }
+
+    struct UserService {
+        repo: Dyn<dyn Repository>,
+    }
+
+    impl Service for UserService {
+        type Dependencies = Dyn<dyn Repository>;
+
+        fn create(repo: Self::Dependencies) -> Self {
+            UserService { repo }
+        }
+    }
+
+    #[test]
+    fn test_dyn_dependency_resolves_bound_interface() {
+        let container = Container::new();
+        container.singleton(PostgresRepository);
+        container.bind::<dyn Repository, PostgresRepository>(|c| c as Arc<dyn Repository>);
+        container.provide::<UserService>();
+
+        let svc = container.get::<UserService>().unwrap();
+        assert_eq!(svc.repo.find(), "row");
+    }
+
+    struct OptionalUserService {
+        repo: Option<Dyn<dyn Repository>>,
+    }
+
+    impl Service for OptionalUserService {
+        type Dependencies = Option<Dyn<dyn Repository>>;
+
+        fn create(repo: Self::Dependencies) -> Self {
+            OptionalUserService { repo }
+        }
+    }
+
+    #[test]
+    fn test_optional_dyn_dependency_missing_binding() {
+        let container = Container::new();
+        container.provide::<OptionalUserService>();
+
+        let svc = container.get::<OptionalUserService>().unwrap();
+        assert!(svc.repo.is_none());
+    }
+
+    // `provide`'s closure runs through `Container::lazy`, which can't return
+    // a `Result` for `with_resolution_frame` to propagate - the cycle guard
+    // panics instead, same as `Container::factory`'s autowired path.
+    #[test]
+    #[should_panic(expected = "CircularDependency")]
+    fn test_provide_detects_self_cycle() {
+        struct SelfReferential {
+            #[allow(dead_code)]
+            other: Arc<SelfReferential>,
+        }
+
+        impl Service for SelfReferential {
+            type Dependencies = Arc<SelfReferential>;
+
+            fn create(other: Self::Dependencies) -> Self {
+                SelfReferential { other }
+            }
+        }
+
+        let container = Container::new();
+        container.provide::<SelfReferential>();
+
+        let _ = container.get::<SelfReferential>();
+    }
+
+    struct FnUserService {
+        db: Arc<Database>,
+    }
+
+    fn make_fn_user_service(db: Arc<Database>) -> FnUserService {
+        FnUserService { db }
+    }
+
+    #[test]
+    fn test_provide_fn_resolves_single_argument() {
+        let container = Container::new();
+        container.singleton(Database {
+            url: "postgres://localhost".into(),
+        });
+        container.provide_fn(make_fn_user_service);
+
+        let svc = container.get::<FnUserService>().unwrap();
+        assert_eq!(svc.db.url, "postgres://localhost");
+    }
+
+    #[test]
+    fn test_provide_fn_zero_arguments() {
+        let container = Container::new();
+        container.provide_fn(|| Config { debug: true });
+
+        let config = container.get::<Config>().unwrap();
+        assert!(config.debug);
+    }
+
+    #[test]
+    fn test_provide_fn_multiple_arguments() {
+        struct Combined {
+            db: Arc<Database>,
+            cache: Arc<Cache>,
+        }
+
+        fn make_combined(db: Arc<Database>, cache: Arc<Cache>) -> Combined {
+            Combined { db, cache }
+        }
+
+        let container = Container::new();
+        container.singleton(Database {
+            url: "postgres://localhost".into(),
+        });
+        container.provide::<Cache>();
+        container.provide_fn(make_combined);
+
+        let combined = container.get::<Combined>().unwrap();
+        assert_eq!(combined.db.url, "postgres://localhost");
+        let _ = combined.cache;
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to resolve arguments")]
+    fn test_provide_fn_missing_argument_panics() {
+        let container = Container::new();
+        container.provide_fn(make_fn_user_service);
+
+        let _ = container.get::<FnUserService>();
+    }
 }
 